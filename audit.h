@@ -3,6 +3,52 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+/**
+ * Outcome of [`parse_web_audit_ffi`](parse_web_audit_ffi)
+ *
+ * `audit.h` prefixes the variants with the enum name, i.e. `AUDIT_STATUS_SUCCESS`.
+ */
+typedef enum {
+  /**
+   * `payload` is the audit as json
+   */
+  AUDIT_STATUS_SUCCESS,
+  /**
+   * The source pointer was null, `payload` is null
+   */
+  AUDIT_STATUS_NULL_INPUT,
+  /**
+   * The source was not valid UTF-8, `payload` is an error message
+   */
+  AUDIT_STATUS_INVALID_UTF8,
+  /**
+   * The source is not a Web Audit, `payload` is an error message
+   */
+  AUDIT_STATUS_PARSE_FAILED,
+  /**
+   * The audit could not be converted to json, `payload` is an error message
+   */
+  AUDIT_STATUS_SERIALIZE_FAILED,
+  /**
+   * The parser panicked, `payload` is an error message
+   */
+  AUDIT_STATUS_PANICKED,
+} AuditStatus;
+
+/**
+ * Status and payload returned across the C boundary
+ */
+typedef struct {
+  /**
+   * What happened, only [`AuditStatus::Success`](AuditStatus::Success) carries an audit
+   */
+  AuditStatus status;
+  /**
+   * Json or error message depending on `status`, free with [`free_as_json`](free_as_json)
+   */
+  char *payload;
+} AuditResult;
+
 /**
  * Free a C-String
  */
@@ -11,4 +57,4 @@ void free_as_json(char *s);
 /**
  * Given a pointer to a C-String, parse a NEU Web Audit
  */
-char *parse_web_audit_ffi(const char *src);
+AuditResult parse_web_audit_ffi(const char *src);
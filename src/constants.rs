@@ -1,23 +1,39 @@
+//! Data model shared by the parser and GraduateNU.
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
+/// Registrar codes: NUPaths, seasons, statuses and grades
 pub mod abbreviations {
     use super::{Deserialize, Display, Serialize};
     use std::{borrow::Cow, convert::TryFrom};
-    #[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
+    /// NUpath attributes, the NEU core curriculum
+    #[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub enum NUPath {
+        /// Natural/Designed World
         ND,
+        /// Creative Expression/Innovation
         EI,
+        /// Interpreting Culture
         IC,
+        /// Formal/Quantitative Reasoning
         FQ,
+        /// Societies/Institutions
         SI,
+        /// Analyzing/Using Data
         AD,
+        /// Difference/Diversity
         DD,
+        /// Ethical Reasoning
         ER,
+        /// First Year Writing
         WF,
+        /// Advanced Writing in the Disciplines
         WD,
+        /// Writing Intensive
         WI,
+        /// Integration Experience
         EX,
+        /// Capstone Experience
         CE,
     }
 
@@ -65,10 +81,12 @@ pub mod abbreviations {
         }
     }
 
-    #[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+    /// Term of the academic year as abbreviated on the audit
+    #[derive(Debug, Copy, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
     #[allow(unused)]
     pub enum Season {
         /// Fall
+        #[default]
         FL,
         /// Spring
         SP,
@@ -80,12 +98,6 @@ pub mod abbreviations {
         SM,
     }
 
-    impl Default for Season {
-        fn default() -> Self {
-            Self::FL
-        }
-    }
-
     impl Display for Season {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             use Season::*;
@@ -114,7 +126,8 @@ pub mod abbreviations {
         }
     }
 
-    #[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+    /// Progress of a requirement as reported by the audit
+    #[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
     #[allow(unused)]
     pub enum Status {
         /// In Progress
@@ -149,12 +162,170 @@ pub mod abbreviations {
         }
     }
 
-    #[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+    /// Grade earned in a course
+    #[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
+    #[allow(unused)]
+    pub enum Grade {
+        /// A
+        A,
+        /// A-
+        #[serde(rename = "A-")]
+        AMinus,
+        /// B+
+        #[serde(rename = "B+")]
+        BPlus,
+        /// B
+        B,
+        /// B-
+        #[serde(rename = "B-")]
+        BMinus,
+        /// C+
+        #[serde(rename = "C+")]
+        CPlus,
+        /// C
+        C,
+        /// C-
+        #[serde(rename = "C-")]
+        CMinus,
+        /// D+
+        #[serde(rename = "D+")]
+        DPlus,
+        /// D
+        D,
+        /// D-
+        #[serde(rename = "D-")]
+        DMinus,
+        /// Failing
+        F,
+        /// Satisfactory
+        S,
+        /// Unsatisfactory
+        U,
+        /// Transfer credit
+        T,
+        /// Withdrawn
+        W,
+        /// Incomplete
+        I,
+        /// Audited, no credit
+        AU,
+        /// In Progress
+        IP,
+        /// Not Reported
+        NR,
+    }
+
+    impl Grade {
+        /// Quality points per credit hour, `None` for grades outside the GPA.
+        pub fn quality_points(self) -> Option<f32> {
+            use Grade::*;
+            match self {
+                A => Some(4.0),
+                AMinus => Some(3.667),
+                BPlus => Some(3.333),
+                B => Some(3.0),
+                BMinus => Some(2.667),
+                CPlus => Some(2.333),
+                C => Some(2.0),
+                CMinus => Some(1.667),
+                DPlus => Some(1.333),
+                D => Some(1.0),
+                DMinus => Some(0.667),
+                F => Some(0.0),
+                S | U | T | W | I | AU | IP | NR => None,
+            }
+        }
+
+        /// No grade has been posted yet.
+        pub fn is_in_progress(self) -> bool {
+            matches!(self, Grade::IP | Grade::NR)
+        }
+
+        /// Dropped after the add/drop deadline.
+        pub fn is_withdrawal(self) -> bool {
+            self == Grade::W
+        }
+
+        /// No credit was earned.
+        pub fn is_failing(self) -> bool {
+            matches!(self, Grade::F | Grade::U)
+        }
+
+        /// Credit earned at another institution or by exam.
+        pub fn is_transfer(self) -> bool {
+            self == Grade::T
+        }
+    }
+
+    impl Display for Grade {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            use Grade::*;
+            match self {
+                A => write!(f, "A"),
+                AMinus => write!(f, "A-"),
+                BPlus => write!(f, "B+"),
+                B => write!(f, "B"),
+                BMinus => write!(f, "B-"),
+                CPlus => write!(f, "C+"),
+                C => write!(f, "C"),
+                CMinus => write!(f, "C-"),
+                DPlus => write!(f, "D+"),
+                D => write!(f, "D"),
+                DMinus => write!(f, "D-"),
+                F => write!(f, "F"),
+                S => write!(f, "S"),
+                U => write!(f, "U"),
+                T => write!(f, "T"),
+                W => write!(f, "W"),
+                I => write!(f, "I"),
+                AU => write!(f, "AU"),
+                IP => write!(f, "IP"),
+                NR => write!(f, "NR"),
+            }
+        }
+    }
+
+    impl TryFrom<String> for Grade {
+        type Error = String;
+        fn try_from(value: String) -> Result<Self, Self::Error> {
+            use Grade::*;
+            match value.as_str() {
+                "A" => Ok(A),
+                "A-" => Ok(AMinus),
+                "B+" => Ok(BPlus),
+                "B" => Ok(B),
+                "B-" => Ok(BMinus),
+                "C+" => Ok(CPlus),
+                "C" => Ok(C),
+                "C-" => Ok(CMinus),
+                "D+" => Ok(DPlus),
+                "D" => Ok(D),
+                "D-" => Ok(DMinus),
+                "F" => Ok(F),
+                "S" => Ok(S),
+                "U" => Ok(U),
+                "T" => Ok(T),
+                "W" => Ok(W),
+                "I" => Ok(I),
+                "AU" => Ok(AU),
+                "IP" => Ok(IP),
+                "NR" => Ok(NR),
+                _ => Err(String::from("ERROR")),
+            }
+        }
+    }
+
+    /// Term of the academic year as named by GraduateNU
+    #[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
     #[allow(unused)]
     pub enum SeasonWord {
+        /// Fall
         Fall,
+        /// Spring
         Spring,
+        /// Summer 1
         Summer1,
+        /// Summer 2
         Summer2,
     }
 
@@ -171,49 +342,296 @@ pub mod abbreviations {
     }
 }
 
+/// Northeastern term ids
+pub mod terms {
+    use super::abbreviations::{Season, SeasonWord};
+    use super::{Deserialize, Display, Serialize};
+    use chrono::{Datelike, NaiveDate};
+    use std::{convert::TryFrom, str::FromStr};
+
+    /// Northeastern unique identifier of a term, i.e. 202110 for Fall 2020.
+    ///
+    /// The first four digits are the year the academic year ends in, the last two the
+    /// season. Ids order chronologically.
+    #[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[serde(try_from = "isize", into = "isize")]
+    pub struct TermId(isize);
+
+    impl TermId {
+        /// Term of `season` in the academic year ending in `academic_year`.
+        pub fn new(season: Season, academic_year: isize) -> Self {
+            use Season::*;
+            let suffix = match season {
+                FL => 10,
+                SP => 30,
+                S1 => 40,
+                SM => 50,
+                S2 => 60,
+            };
+            Self(academic_year * 100 + suffix)
+        }
+
+        /// Term as printed on the audit, i.e. FL20 is Fall 2020 and so 202110.
+        pub fn from_audit(season: Season, year: isize) -> Self {
+            let year = 2000 + year;
+            match season {
+                Season::FL => Self::new(season, year + 1),
+                _ => Self::new(season, year),
+            }
+        }
+
+        /// Term in session on `date`, summers are split into halves.
+        pub fn containing(date: NaiveDate) -> Self {
+            let season = match date.month() {
+                1..=4 => Season::SP,
+                5..=6 => Season::S1,
+                7..=8 => Season::S2,
+                _ => Season::FL,
+            };
+            Self::from_audit(season, (date.year() % 100) as isize)
+        }
+
+        /// Term of `season` in the academic year ending in `academic_year`.
+        pub fn from_season_word(season: SeasonWord, academic_year: isize) -> Self {
+            let season = match season {
+                SeasonWord::Fall => Season::FL,
+                SeasonWord::Spring => Season::SP,
+                SeasonWord::Summer1 => Season::S1,
+                SeasonWord::Summer2 => Season::S2,
+            };
+            Self::new(season, academic_year)
+        }
+
+        /// Season of the term.
+        pub fn season(self) -> Season {
+            match self.0 % 100 {
+                10 => Season::FL,
+                30 => Season::SP,
+                40 => Season::S1,
+                50 => Season::SM,
+                _ => Season::S2,
+            }
+        }
+
+        /// Season as named by GraduateNU, a full summer is its first half.
+        pub fn season_word(self) -> SeasonWord {
+            match self.season() {
+                Season::FL => SeasonWord::Fall,
+                Season::SP => SeasonWord::Spring,
+                Season::S1 | Season::SM => SeasonWord::Summer1,
+                Season::S2 => SeasonWord::Summer2,
+            }
+        }
+
+        /// Year the academic year of the term ends in.
+        pub fn academic_year(self) -> isize {
+            self.0 / 100
+        }
+
+        /// Year the term takes place in, a fall is the year before its academic year.
+        pub fn calendar_year(self) -> isize {
+            match self.season() {
+                Season::FL => self.academic_year() - 1,
+                _ => self.academic_year(),
+            }
+        }
+
+        /// `(Season, academic year)` of the term.
+        pub fn to_season_year(self) -> (Season, isize) {
+            (self.season(), self.academic_year())
+        }
+
+        /// The term after this one, a full summer is followed by fall.
+        pub fn next(self) -> Self {
+            use Season::*;
+            let year = self.academic_year();
+            match self.season() {
+                FL => Self::new(SP, year),
+                SP => Self::new(S1, year),
+                S1 => Self::new(S2, year),
+                S2 | SM => Self::new(FL, year + 1),
+            }
+        }
+
+        /// The term before this one, a full summer is preceded by spring.
+        pub fn previous(self) -> Self {
+            use Season::*;
+            let year = self.academic_year();
+            match self.season() {
+                FL => Self::new(S2, year - 1),
+                SP => Self::new(FL, year),
+                S1 | SM => Self::new(SP, year),
+                S2 => Self::new(S1, year),
+            }
+        }
+    }
+
+    impl TryFrom<isize> for TermId {
+        type Error = String;
+        fn try_from(value: isize) -> Result<Self, Self::Error> {
+            match (value / 100, value % 100) {
+                (1000..=9999, 10) | (1000..=9999, 30..=60) if value % 10 == 0 => Ok(Self(value)),
+                _ => Err(format!("invalid term id {}", value)),
+            }
+        }
+    }
+
+    impl From<TermId> for isize {
+        fn from(term: TermId) -> Self {
+            term.0
+        }
+    }
+
+    impl From<(Season, isize)> for TermId {
+        fn from((season, academic_year): (Season, isize)) -> Self {
+            Self::new(season, academic_year)
+        }
+    }
+
+    /// i.e. "Fall 2020", "Summer 1 2021" or "Summer 2021" for a full summer
+    impl Display for TermId {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let season = match self.season() {
+                Season::FL => "Fall",
+                Season::SP => "Spring",
+                Season::S1 => "Summer 1",
+                Season::S2 => "Summer 2",
+                Season::SM => "Summer",
+            };
+            write!(f, "{} {}", season, self.calendar_year())
+        }
+    }
+
+    impl FromStr for TermId {
+        type Err = String;
+        fn from_str(label: &str) -> Result<Self, Self::Err> {
+            let invalid = || format!("invalid term {:?}", label);
+            let (season, year) = label.trim().rsplit_once(' ').ok_or_else(invalid)?;
+            let year: isize = year.parse().map_err(|_| invalid())?;
+            let season = match season.to_lowercase().as_str() {
+                "fall" => Season::FL,
+                "spring" => Season::SP,
+                "summer 1" | "summer1" => Season::S1,
+                "summer 2" | "summer2" => Season::S2,
+                "summer" | "full summer" => Season::SM,
+                _ => return Err(invalid()),
+            };
+            match season {
+                Season::FL => TermId::try_from(Self::new(season, year + 1).0),
+                _ => TermId::try_from(Self::new(season, year).0),
+            }
+            .map_err(|_| invalid())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn term_ids_round_trip() {
+            let fall = TermId::try_from(202110).unwrap();
+            assert_eq!(fall, TermId::from_audit(Season::FL, 20));
+            assert_eq!(fall, TermId::from_season_word(SeasonWord::Fall, 2021));
+            assert_eq!(fall.to_season_year(), (Season::FL, 2021));
+            assert_eq!(fall.to_string(), "Fall 2020");
+            assert_eq!("Fall 2020".parse(), Ok(fall));
+            assert_eq!("summer 2021".parse::<TermId>().map(isize::from), Ok(202150));
+
+            assert_eq!(fall.previous().to_string(), "Summer 2 2020");
+            assert_eq!(fall.next().to_string(), "Spring 2021");
+            assert_eq!(
+                TermId::new(Season::SM, 2021).next(),
+                TermId::new(Season::FL, 2022)
+            );
+            assert!(fall < fall.next() && fall.previous() < fall);
+
+            assert!(TermId::try_from(202120).is_err());
+            assert!(TermId::try_from(2021).is_err());
+            assert!("Winter 2020".parse::<TermId>().is_err());
+
+            assert_eq!(serde_json::to_string(&fall).unwrap(), "202110");
+            assert_eq!(serde_json::from_str::<TermId>("202110").unwrap(), fall);
+            assert!(serde_json::from_str::<TermId>("202120").is_err());
+        }
+    }
+}
+
+/// Course requirements and prerequisites
 pub mod courses {
-    use super::{Deserialize, Serialize};
+    use super::{Deserialize, Display, Serialize};
+    /// A requirement that can be satisfied by courses
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub enum Requirement {
+        /// Any one of the inner requirements
         OrCourse(Vec<Requirement>),
+        /// Every one of the inner requirements
         AndCourse(Vec<Requirement>),
+        /// Credits from a range of course numbers
         CourseRange(CourseRange),
+        /// One specific course
         RequiredCourse(Course),
     }
 
+    /// Credits to earn from one or more ranges of courses
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct CourseRange {
-        credits_required: isize,
-        ranges: Vec<SubjectRange>,
+        /// Credits needed from `ranges`
+        pub credits_required: isize,
+        /// Courses that count towards the credits
+        pub ranges: Vec<SubjectRange>,
     }
+    /// Course numbers of one subject, i.e. CS 2500 to CS 2999
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct SubjectRange {
-        subject: String,
-        id_range_start: isize,
-        id_range_end: isize,
+        /// Subject i.e. "CS"
+        pub subject: String,
+        /// First course number, inclusive
+        pub id_range_start: isize,
+        /// Last course number, inclusive
+        pub id_range_end: isize,
     }
+    /// A specific course
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct Course {
-        class_id: isize,
-        subject: String,
-        is_required: bool,
+        /// Course number i.e. 2500
+        pub class_id: isize,
+        /// Subject i.e. "CS"
+        pub subject: String,
+        /// True if the course must be taken rather than being one option
+        pub is_required: bool,
     }
 
+    /// Prerequisite tree of a course
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub enum NeuPreqs {
+        /// All of the prerequisites
         And(Vec<Prereq>),
+        /// Any one of the prerequisites
         Or(Vec<Prereq>),
+        /// A single prerequisite
         One(Prereq),
     }
 
+    /// A course required before, or alongside, another
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct Prereq {
-        class_id: String,
-        subject: String,
-        missing: Option<bool>,
+        /// Course number i.e. "2500"
+        pub class_id: String,
+        /// Subject i.e. "CS"
+        pub subject: String,
+        /// True if the course is not in the catalog
+        pub missing: Option<bool>,
+    }
+
+    impl Display for Prereq {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{} {}", self.subject, self.class_id)
+        }
     }
 }
 
+/// Major definitions in GraduateNU's scraped format
 pub mod majors {
     use super::{
         abbreviations::NUPath,
@@ -222,93 +640,170 @@ pub mod majors {
     use super::{Deserialize, Serialize};
     use std::collections::HashMap;
 
+    /// Requirements of a major for one catalog year
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct Major {
-        name: String,
-        requirement_groups: Vec<String>,
-        requirement_group_map: HashMap<String, MajorRequirement>,
-        year_version: isize,
-        is_language_required: bool,
-        total_credits_required: isize,
-        nu_paths: Vec<NUPath>,
+        /// i.e. "Computer Science, BSCS"
+        pub name: String,
+        /// Names of the requirement groups, in catalog order
+        pub requirement_groups: Vec<String>,
+        /// Requirement group by name
+        pub requirement_group_map: HashMap<String, MajorRequirement>,
+        /// Catalog year the definition applies to
+        pub year_version: isize,
+        /// True if a foreign language is required
+        pub is_language_required: bool,
+        /// Credits required to graduate
+        pub total_credits_required: isize,
+        /// NUPaths the major requires
+        pub nu_paths: Vec<NUPath>,
+        /// Concentrations the major offers, if any
+        #[serde(default)]
+        pub concentrations: Option<Concentration>,
     }
 
+    /// Concentrations a major offers and how many to choose
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct Concentration {
-        min_options: isize,
-        max_options: isize,
-        requirements_group_map: Vec<MajorRequirement>,
+        /// Fewest concentrations a student may declare
+        pub min_options: isize,
+        /// Most concentrations a student may declare
+        pub max_options: isize,
+        /// Requirements of each concentration
+        pub requirements_group_map: Vec<MajorRequirement>,
     }
 
+    /// A requirement group of a major
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub enum MajorRequirement {
+        /// Every requirement of the section
         And(Section),
+        /// Enough requirements of the section
         Or(Section),
+        /// Credits from ranges of courses
         Range(SectionRange),
     }
 
-    #[derive(Debug, Clone, Serialize, Deserialize)]
+    impl MajorRequirement {
+        /// Name of the group, i.e. "Computer Science Fundamental Courses"
+        pub fn name(&self) -> &str {
+            match self {
+                MajorRequirement::And(section) | MajorRequirement::Or(section) => &section.name,
+                MajorRequirement::Range(range) => &range.name,
+            }
+        }
+
+        /// True if this is the block of concentration `name`, i.e. "Software Concentration"
+        /// for "Software", ignoring case.
+        pub fn is_concentration(&self, name: &str) -> bool {
+            let block = format!("{} Concentration", name.trim());
+            self.name().trim().eq_ignore_ascii_case(&block)
+        }
+    }
+
+    /// Named list of requirements with optional credit bounds
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
     pub struct Section {
-        requirements: Vec<Requirement>,
-        num_credits_min: Option<isize>,
-        num_credits_max: Option<isize>,
-        name: String,
+        /// Courses that satisfy the section
+        pub requirements: Vec<Requirement>,
+        /// Fewest credits that satisfy the section
+        pub num_credits_min: Option<isize>,
+        /// Most credits that count towards the section
+        pub num_credits_max: Option<isize>,
+        /// Requirements of an `Or` section that must be satisfied, one if unset
+        #[serde(default)]
+        pub num_courses: Option<isize>,
+        /// i.e. "Computer Science Fundamental Courses"
+        pub name: String,
     }
 
+    /// Named list of course ranges with optional credit bounds
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct SectionRange {
-        requirements: Vec<CourseRange>,
-        num_credits_min: Option<isize>,
-        num_credits_max: Option<isize>,
-        name: String,
+        /// Ranges of courses that satisfy the section
+        pub requirements: Vec<CourseRange>,
+        /// Fewest credits that satisfy the section
+        pub num_credits_min: Option<isize>,
+        /// Most credits that count towards the section
+        pub num_credits_max: Option<isize>,
+        /// i.e. "Computer Science Electives"
+        pub name: String,
     }
 }
 
+/// A student's plan, year by year and term by term
 pub mod schedule {
-    use super::{abbreviations::Season, courses::Prereq, Display};
+    use super::{abbreviations::Season, courses::NeuPreqs, terms::TermId, Display};
     use super::{Deserialize, Serialize};
     use std::collections::HashMap;
 
+    /// A plan of study
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct Schedule {
-        years: Vec<isize>,
-        year_map: HashMap<isize, ScheduleYear>,
-        id: String,
+        /// Academic years of the plan, in order
+        pub years: Vec<isize>,
+        /// Academic year by year
+        pub year_map: HashMap<isize, ScheduleYear>,
+        /// Identifier of the plan
+        pub id: String,
     }
 
+    /// Terms of one academic year
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct ScheduleYear {
-        year: isize,
-        fall: ScheduleTerm,
-        spring: ScheduleTerm,
-        summer1: ScheduleTerm,
-        summer2: ScheduleTerm,
-        is_summer_full: bool,
+        /// Year the academic year ends in
+        pub year: isize,
+        /// Fall term
+        pub fall: ScheduleTerm,
+        /// Spring term
+        pub spring: ScheduleTerm,
+        /// Summer 1 term
+        pub summer1: ScheduleTerm,
+        /// Summer 2 term
+        pub summer2: ScheduleTerm,
+        /// True if the summer is one full term rather than two halves
+        pub is_summer_full: bool,
     }
 
+    /// A term, or a drag and drop placeholder for one
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub enum ScheduleTerm {
+        /// A term
         Term(Term),
         /// Inner value is DndId
         Dnd(String),
     }
 
+    /// Courses of one term
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct Term {
-        season: Season,
-        year: isize,
-        term_id: isize,
-        id: isize,
-        status: Status,
-        classes: Vec<ScheduleCourse>,
+        /// Season of the term
+        pub season: Season,
+        /// Two digit year of the season
+        pub year: isize,
+        /// Northeastern unique identifier
+        pub term_id: TermId,
+        /// Identifier within the schedule
+        pub id: isize,
+        /// What the student does this term
+        pub status: Status,
+        /// Courses taken this term
+        pub classes: Vec<ScheduleCourse>,
     }
 
+    /// What a student does in a term
     #[derive(Debug, Clone, Serialize, Deserialize)]
+    #[allow(clippy::upper_case_acronyms)]
     pub enum Status {
+        /// On co-op
         COOP,
+        /// Taking classes
         CLASSES,
+        /// Neither taking classes nor on co-op
         INACTIVE,
+        /// Inactive, while being hovered in the planner
         HOVERINACTIVE,
+        /// On co-op, while being hovered in the planner
         HOVERCOOP,
     }
 
@@ -325,108 +820,266 @@ pub mod schedule {
         }
     }
 
+    /// A course, or a drag and drop placeholder for one
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub enum AllScheduleCourse {
+        /// A course
         Course(ScheduleCourse),
         /// inner value is dnd id
         Dnd(String),
     }
 
+    /// A course placed in a schedule
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct ScheduleCourse {
-        name: String,
-        class_id: String,
-        subject: String,
-        pre_reqs: Option<Prereq>,
-        co_reqs: Option<Prereq>,
-        num_credits_min: isize,
-        num_credits_max: isize,
+        /// Title of the course
+        pub name: String,
+        /// Course number i.e. "2500"
+        pub class_id: String,
+        /// Subject i.e. "CS"
+        pub subject: String,
+        /// Courses to take first
+        pub pre_reqs: Option<NeuPreqs>,
+        /// Courses to take in the same term
+        pub co_reqs: Option<NeuPreqs>,
+        /// Fewest credits the course can be taken for
+        pub num_credits_min: isize,
+        /// Most credits the course can be taken for
+        pub num_credits_max: isize,
     }
 }
 
+/// Problems found while checking a schedule
 pub mod warnings {
+    use super::terms::TermId;
     use super::{Deserialize, Serialize};
 
+    /// A problem with a term
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct Warning {
-        message: String,
-        term_id: isize,
+        /// Description of the problem
+        pub message: String,
+        /// Term the problem is in
+        pub term_id: TermId,
     }
 
+    /// A problem with a course
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct CourseWarning {
-        subject: String,
-        class_id: isize,
-        warning: Warning,
+        /// Subject i.e. "CS"
+        pub subject: String,
+        /// Course number i.e. 2500
+        pub class_id: isize,
+        /// The problem
+        pub warning: Warning,
     }
 
+    /// Every problem found in a schedule
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct WarningContainer {
-        normal_warnings: Vec<Warning>,
-        course_warnings: Vec<CourseWarning>,
+        /// Problems with terms
+        pub normal_warnings: Vec<Warning>,
+        /// Problems with courses
+        pub course_warnings: Vec<CourseWarning>,
     }
 
+    /// A problem with a requirement group
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct RequirementGroupWarning {
-        message: String,
-        requirement_group: isize,
+        /// Description of the problem
+        pub message: String,
+        /// Index of the requirement group
+        pub requirement_group: isize,
     }
 }
 
+/// Courses a student has taken and who the student is
 pub mod tracking {
     use super::majors::Major;
-    use super::schedule::Schedule;
+    use super::schedule::{AllScheduleCourse, Schedule};
+    use super::terms::TermId;
     use super::AllCourses;
+    use super::Display;
     use super::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
 
+    /// Records which courses were taken in which terms
     pub trait CourseTakenTracker {
+        /// True if the course, i.e. "CS 2500", was taken
         fn contains(&self, input: &str) -> bool;
-        fn add_courses(&mut self, to_add: Vec<AllCourses>, term_id: isize);
-        fn add_course(&mut self, to_add: AllCourses, term_id: isize);
-        fn get_term_ids(&self, course: String) -> Vec<isize>;
+        /// Record courses as taken in `term_id`
+        fn add_courses(&mut self, to_add: Vec<AllCourses>, term_id: TermId);
+        /// Record a course as taken in `term_id`
+        fn add_course(&mut self, to_add: AllCourses, term_id: TermId);
+        /// Terms the course, i.e. "CS 2500", was taken in
+        fn get_term_ids(&self, course: String) -> Vec<TermId>;
+    }
+
+    /// Terms each course was taken in, by "SUBJECT CLASSID"
+    #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+    pub struct CourseTracker {
+        /// Term ids, in order, by "SUBJECT CLASSID"
+        courses: BTreeMap<String, Vec<TermId>>,
+    }
+
+    impl CourseTracker {
+        /// A tracker without any courses.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Record `subject` `class_id` as taken in `term_id`.
+        pub fn add(&mut self, subject: &str, class_id: impl Display, term_id: TermId) {
+            let terms = self
+                .courses
+                .entry(format!("{} {}", subject, class_id))
+                .or_default();
+            if let Err(index) = terms.binary_search(&term_id) {
+                terms.insert(index, term_id);
+            }
+        }
+
+        /// Courses taken in more than one term, by "SUBJECT CLASSID".
+        pub fn repeated(&self) -> impl Iterator<Item = &str> {
+            self.courses
+                .iter()
+                .filter(|(_, terms)| terms.len() > 1)
+                .map(|(course, _)| course.as_str())
+        }
     }
 
+    impl CourseTakenTracker for CourseTracker {
+        fn contains(&self, input: &str) -> bool {
+            self.courses.contains_key(input)
+        }
+
+        fn add_courses(&mut self, to_add: Vec<AllCourses>, term_id: TermId) {
+            to_add
+                .into_iter()
+                .for_each(|course| self.add_course(course, term_id));
+        }
+
+        fn add_course(&mut self, to_add: AllCourses, term_id: TermId) {
+            match to_add {
+                AllCourses::Parser(course) => self.add(&course.subject, course.class_id, term_id),
+                AllCourses::Model(AllScheduleCourse::Course(course)) => {
+                    self.add(&course.subject, &course.class_id, term_id)
+                }
+                AllCourses::Model(AllScheduleCourse::Dnd(_)) => (),
+            }
+        }
+
+        fn get_term_ids(&self, course: String) -> Vec<TermId> {
+            self.courses.get(&course).cloned().unwrap_or_default()
+        }
+    }
+
+    /// A student and their plan
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct UserData {
-        full_name: Option<String>,
-        academic_year: Option<isize>,
-        graduation_year: Option<isize>,
-        major: Option<Major>,
-        minors: Option<Vec<String>>,
-        plan: Option<Schedule>,
+        /// Name of the student
+        pub full_name: Option<String>,
+        /// Year of study i.e. 3 for a middler
+        pub academic_year: Option<isize>,
+        /// Expected year of graduation
+        pub graduation_year: Option<isize>,
+        /// Declared major
+        pub major: Option<Major>,
+        /// Declared minors
+        pub minors: Option<Vec<String>>,
+        /// Plan of study
+        pub plan: Option<Schedule>,
     }
 }
 
 use parser_types::NEUCourse;
 use schedule::AllScheduleCourse;
+/// A course as scraped from the course catalog or as placed in a schedule
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AllCourses {
+    /// Scraped from the course catalog
     Parser(NEUCourse),
+    /// Placed in a schedule
     Model(AllScheduleCourse),
 }
 
+/// Output of the audit parser
 pub mod parser_types {
     use super::{
-        abbreviations::{NUPath, Season},
-        courses::Prereq,
+        abbreviations::{Grade, NUPath, Season, Status},
+        courses::{NeuPreqs, Prereq},
+        majors::MajorRequirement,
+        terms::TermId,
     };
-    use super::{Deserialize, Serialize};
-    use chrono::NaiveDate;
+    use super::{Deserialize, Display, Serialize};
+    use chrono::{NaiveDate, NaiveDateTime};
     use std::collections::HashMap;
+    use std::convert::TryFrom;
 
-    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+    /// A course listed on the audit
+    #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct CompleteCourse {
         /// True if course is Honors
         pub hon: bool,
         /// Subject i.e. "CS" or Psychology
         pub subject: String,
+        /// Course number i.e. 2500
         pub class_id: isize,
+        /// Title of the course
         pub name: String,
+        /// Credits earned or attempted
         pub credit_hours: f32,
+        /// `None` when the grade column is blank
+        pub grade: Option<Grade>,
+        /// Season the course was taken
         pub season: Season,
+        /// Two digit year of the season
         pub year: isize,
         /// Northeastern unique identifier
-        pub term_id: isize,
+        pub term_id: TermId,
+        /// Marked `>R`, a later attempt replaces this one
+        #[serde(default)]
+        pub repeated: bool,
+        /// Graded `W`, dropped after the add/drop deadline
+        #[serde(default)]
+        pub withdrawn: bool,
+        /// Marked `>X` or `>D`, left out of credit and GPA
+        #[serde(default)]
+        pub excluded: bool,
+    }
+
+    impl CompleteCourse {
+        /// An ungraded course without credits or a title, taken in `term_id`.
+        pub fn new(subject: String, class_id: isize, term_id: TermId) -> Self {
+            Self {
+                hon: false,
+                subject,
+                class_id,
+                name: String::new(),
+                credit_hours: 0_f32,
+                grade: None,
+                season: term_id.season(),
+                year: term_id.calendar_year() % 100,
+                term_id,
+                repeated: false,
+                withdrawn: false,
+                excluded: false,
+            }
+        }
+
+        /// False for repeated, withdrawn and excluded attempts, which earn no credit and
+        /// are left out of the GPA.
+        pub fn counts(&self) -> bool {
+            !(self.repeated || self.withdrawn || self.excluded)
+        }
+
+        /// True if both are the same course taken in the same term, however the audit
+        /// titled them.
+        pub fn is_same_attempt(&self, other: &CompleteCourse) -> bool {
+            self.subject == other.subject
+                && self.class_id == other.class_id
+                && self.term_id == other.term_id
+        }
     }
 
     impl PartialEq for CompleteCourse {
@@ -438,79 +1091,397 @@ pub mod parser_types {
         }
     }
 
+    /// Where credit earned outside of Northeastern courses comes from
+    #[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
+    pub enum CreditSource {
+        /// Course at another institution
+        #[serde(rename = "TR")]
+        Transfer,
+        /// Advanced Placement exam
+        #[serde(rename = "AP")]
+        AdvancedPlacement,
+        /// International Baccalaureate exam
+        #[serde(rename = "IB")]
+        InternationalBaccalaureate,
+        /// College Level Examination Program exam
+        #[serde(rename = "CLEP")]
+        CollegeLevelExam,
+        /// Any other exam
+        #[serde(rename = "EX")]
+        Exam,
+    }
+
+    impl TryFrom<String> for CreditSource {
+        type Error = String;
+        fn try_from(value: String) -> Result<Self, Self::Error> {
+            use CreditSource::*;
+            match value.as_str() {
+                "TR" => Ok(Transfer),
+                "AP" => Ok(AdvancedPlacement),
+                "IB" => Ok(InternationalBaccalaureate),
+                "CLEP" => Ok(CollegeLevelExam),
+                "EX" => Ok(Exam),
+                _ => Err(String::from("ERROR")),
+            }
+        }
+    }
+
+    /// Transfer, AP, IB or exam credit and the Northeastern course it counts as
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct TransferCredit {
+        /// Kind of credit
+        pub source: CreditSource,
+        /// i.e. "Boston University" or the exam taken
+        pub institution: String,
+        /// Subject of the equivalent course i.e. "MATH"
+        pub subject: String,
+        /// Course number of the equivalent course i.e. 1341
+        pub class_id: isize,
+        /// Title of the equivalent course
+        pub name: String,
+        /// Credits awarded
+        pub credit_hours: f32,
+        /// Usually `T`
+        pub grade: Option<Grade>,
+        /// Term the credit was earned in, when printed
+        pub term_id: Option<TermId>,
+    }
+
+    impl TransferCredit {
+        /// The equivalent course, credit without a term is placed in `entry_term`.
+        pub fn to_course(&self, entry_term: TermId) -> CompleteCourse {
+            let term_id = self.term_id.unwrap_or(entry_term);
+            CompleteCourse {
+                name: self.name.clone(),
+                credit_hours: self.credit_hours,
+                grade: self.grade,
+                ..CompleteCourse::new(self.subject.clone(), self.class_id, term_id)
+            }
+        }
+    }
+
+    /// A course, or range of courses, from a `Course List:`
     #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
     pub struct Requirement {
+        /// Course number, or first of a range
         pub class_id: isize,
+        /// Subject i.e. "CS"
         pub subject: Option<String>,
+        /// Courses needed from the list
         pub num_required: Option<isize>,
+        /// Last course number of a range
         pub class_id_2: Option<isize>,
+        /// Course numbers of the list
         pub list: Vec<isize>,
     }
 
+    /// Outcome of one NUPath across every NUPath block of the audit
     #[derive(Debug, Clone, Serialize, Deserialize)]
-    pub struct Course {
-        nupaths: Vec<NUPath>,
-        courses: Vec<CompleteCourse>,
+    pub struct NUPathResult {
+        /// i.e. "Natural/Designed World"
+        pub name: String,
+        /// Progress towards the NUPath
+        pub status: Status,
+        /// Courses the audit lists as satisfying the NUPath
+        pub courses: Vec<CompleteCourse>,
     }
 
+    /// A requirement block of the audit, its numbered sub-requirements and the courses
+    /// the registrar applied to it
     #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct AuditSection {
+        /// Progress towards the block
+        pub status: Status,
+        /// And/Or group holding the block name, credit bounds and course options
+        pub requirement: MajorRequirement,
+        /// Courses needed from an Or group
+        pub num_courses: Option<isize>,
+        /// Credits and courses applied so far, from "EARNED:"
+        #[serde(default)]
+        pub earned: Option<Counter>,
+        /// Credits and courses still missing, from "NEEDS:"
+        #[serde(default)]
+        pub needs: Option<Counter>,
+        /// Courses the audit applied to the block
+        pub courses: Vec<CompleteCourse>,
+        /// Numbered sub-requirements
+        pub sections: Vec<AuditSection>,
+    }
+
+    impl AuditSection {
+        /// Courses that must be taken from the block's `Course List:`.
+        ///
+        /// "Complete 2 courses from the following" wins, otherwise the EARNED and NEEDS
+        /// course counters are added up.
+        pub fn num_required(&self) -> Option<isize> {
+            self.num_courses.or_else(|| {
+                let needs = self.needs.and_then(|needs| needs.courses)?;
+                let earned = self.earned.and_then(|earned| earned.courses);
+                Some(earned.unwrap_or(0) + needs)
+            })
+        }
+    }
+
+    /// An "EARNED:" or "NEEDS:" counter of a requirement block, i.e. "8.00 HOURS 2 COURSES"
+    #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+    pub struct Counter {
+        /// Semester hours
+        pub hours: Option<f32>,
+        /// Number of courses
+        pub courses: Option<isize>,
+    }
+
+    /// A course in GraduateNU's camelCase format
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct InitialCourse {
+        /// True if course is Honors
+        pub hon: bool,
+        /// Subject i.e. "CS"
+        pub subject: String,
+        /// Course number i.e. 2500
+        pub class_id: isize,
+        /// Title of the course
+        pub name: String,
+        /// Credits earned or attempted
+        pub credit_hours: f32,
+        /// Season the course was taken
+        pub season: Season,
+        /// Two digit year of the season
+        pub year: isize,
+        /// Northeastern unique identifier
+        pub term_id: TermId,
+    }
+
+    impl From<&CompleteCourse> for InitialCourse {
+        fn from(course: &CompleteCourse) -> Self {
+            Self {
+                hon: course.hon,
+                subject: course.subject.clone(),
+                class_id: course.class_id,
+                name: course.name.clone(),
+                credit_hours: course.credit_hours,
+                season: course.season,
+                year: course.year,
+                term_id: course.term_id,
+            }
+        }
+    }
+
+    /// A `Course List:` entry in GraduateNU's camelCase format
+    #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    pub struct InitialRequirement {
+        /// Course number, or first of a range
+        pub class_id: isize,
+        /// Subject i.e. "CS"
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub subject: Option<String>,
+        /// Courses needed from the list
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub num_required: Option<isize>,
+        /// Last course number of a range
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub class_id2: Option<isize>,
+        /// Course numbers of the list
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub list: Vec<isize>,
+    }
+
+    impl From<&Requirement> for InitialRequirement {
+        fn from(requirement: &Requirement) -> Self {
+            Self {
+                class_id: requirement.class_id,
+                subject: requirement.subject.clone(),
+                num_required: requirement.num_required,
+                class_id2: requirement.class_id_2,
+                list: requirement.list.clone(),
+            }
+        }
+    }
+
+    /// NUPaths and courses
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+    pub struct Course {
+        /// NUPaths
+        pub nupaths: Vec<NUPath>,
+        /// Courses
+        pub courses: Vec<InitialCourse>,
+    }
+
+    /// What remains to be taken
+    #[derive(Debug, Clone, Serialize, Deserialize, Default)]
     pub struct Requirements {
-        nupaths: Vec<Requirement>,
-        courses: Vec<CompleteCourse>,
+        /// NUPaths
+        pub nupaths: Vec<NUPath>,
+        /// Courses
+        pub courses: Vec<InitialRequirement>,
+    }
+
+    /// Student fields printed above `GRADUATION DATE:`, `None` when missing
+    #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+    pub struct AuditHeader {
+        /// As printed, i.e. "Husky, Paws"
+        pub name: Option<String>,
+        /// Northeastern University ID, leading zeroes kept
+        pub nuid: Option<String>,
+        /// i.e. "Khoury College of Computer Sciences"
+        pub college: Option<String>,
+        /// i.e. "Bachelor of Science"
+        pub degree: Option<String>,
+        /// As printed, i.e. "Smith, Jane"
+        pub advisor: Option<String>,
+        /// When the audit was run
+        pub prepared: Option<NaiveDateTime>,
+    }
+
+    impl AuditHeader {
+        /// Student name in reading order, i.e. "Paws Husky".
+        pub fn full_name(&self) -> Option<String> {
+            let name = self.name.as_ref()?;
+            Some(match name.split_once(',') {
+                Some((last, first)) => format!("{} {}", first.trim(), last.trim()),
+                None => name.clone(),
+            })
+        }
+    }
+
+    /// Catalog the student's requirements come from, printed as i.e. 202021
+    #[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
+    pub struct CatalogYear {
+        /// Year the catalog starts in, i.e. 2020
+        pub start: isize,
+        /// Year the catalog ends in, i.e. 2021
+        pub end: isize,
+    }
+
+    impl CatalogYear {
+        /// `year_version` of the [`Major`](super::majors::Major) definitions of the catalog.
+        pub fn year_version(self) -> isize {
+            self.start
+        }
+    }
+
+    impl Default for CatalogYear {
+        fn default() -> Self {
+            Self {
+                start: 2020,
+                end: 2021,
+            }
+        }
+    }
+
+    impl TryFrom<isize> for CatalogYear {
+        type Error = String;
+        fn try_from(value: isize) -> Result<Self, Self::Error> {
+            let start = value / 100;
+            let end = start - start % 100 + value % 100;
+            // The century rolls over between i.e. 1999 and 2000
+            let end = if end < start { end + 100 } else { end };
+            match (start, end - start) {
+                (1900..=2999, 1) => Ok(Self { start, end }),
+                _ => Err(format!("invalid catalog year {}", value)),
+            }
+        }
+    }
+
+    impl Display for CatalogYear {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}-{}", self.start, self.end)
+        }
     }
 
+    /// Programs and dates of the audit
     #[derive(Debug, Clone, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
     pub struct Data {
-        majors: Vec<String>,
-        minors: Vec<String>,
-        audit_year: isize,
-        grad_date: NaiveDate,
+        /// Declared majors
+        pub majors: Vec<String>,
+        /// Declared minors
+        pub minors: Vec<String>,
+        /// Year the catalog starts in
+        pub audit_year: isize,
+        /// Expected graduation date
+        pub grad_date: NaiveDate,
     }
 
+    /// GraduateNU's representation of a parsed audit
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct InitialScheduleRep {
-        completed: Requirement,
-        in_progress: Requirement,
-        requirements: Requirements,
-        data: Data,
+        /// Completed courses and NUPaths
+        pub completed: Course,
+        /// In progress courses and NUPaths
+        #[serde(rename = "inprogress")]
+        pub in_progress: Course,
+        /// Remaining requirements
+        pub requirements: Requirements,
+        /// Programs and dates
+        pub data: Data,
     }
 
+    /// Every course of every scraped term
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct NEUParentMap {
-        most_recent_semester: isize,
-        all_term_ids: Vec<isize>,
-        class_map: HashMap<String, NEUClassMap>,
+        /// Latest term scraped
+        pub most_recent_semester: TermId,
+        /// Every term scraped
+        pub all_term_ids: Vec<TermId>,
+        /// Courses of each term, by term id
+        pub class_map: HashMap<String, NEUClassMap>,
     }
 
+    /// Every course of one scraped term
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct NEUClassMap {
-        term_id: isize,
-        class_map: HashMap<String, NEUCourse>,
+        /// Northeastern unique identifier
+        pub term_id: TermId,
+        /// Course by "SUBJECT CLASSID"
+        pub class_map: HashMap<String, NEUCourse>,
     }
 
+    /// A course as scraped from the course catalog
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct NEUCourse {
-        crns: Vec<String>,
-        pre_reqs: Option<Prereq>,
-        co_reqs: Option<Prereq>,
-        max_credits: isize,
-        min_credits: isize,
-        desc: String,
-        class_id: isize,
-        pretty_url: String,
-        name: String,
-        url: String,
-        last_update_time: isize,
-        term_id: isize,
-        host: String,
-        subject: String,
-        opt_prereqs_for: Option<Vec<Prereq>>,
-        prereqs_for: Option<Vec<Prereq>>,
+        /// Sections offered
+        pub crns: Vec<String>,
+        /// Courses to take first
+        pub pre_reqs: Option<NeuPreqs>,
+        /// Courses to take in the same term
+        pub co_reqs: Option<NeuPreqs>,
+        /// Most credits the course can be taken for
+        pub max_credits: isize,
+        /// Fewest credits the course can be taken for
+        pub min_credits: isize,
+        /// Catalog description
+        pub desc: String,
+        /// Course number i.e. 2500
+        pub class_id: isize,
+        /// Catalog page for people
+        pub pretty_url: String,
+        /// Title of the course
+        pub name: String,
+        /// Catalog page the course was scraped from
+        pub url: String,
+        /// When the course was scraped
+        pub last_update_time: isize,
+        /// Northeastern unique identifier
+        pub term_id: TermId,
+        /// Host the course was scraped from
+        pub host: String,
+        /// Subject i.e. "CS"
+        pub subject: String,
+        /// Courses this is an optional prerequisite for
+        pub opt_prereqs_for: Option<Vec<Prereq>>,
+        /// Courses this is a prerequisite for
+        pub prereqs_for: Option<Vec<Prereq>>,
     }
 
+    /// Completed and scheduled courses
     #[derive(Debug, Clone, Serialize, Deserialize)]
     pub struct Schedule {
-        completed: Vec<CompleteCourse>,
-        scheduled: Vec<Vec<String>>,
+        /// Completed courses
+        pub completed: Vec<CompleteCourse>,
+        /// Course names of each upcoming term
+        pub scheduled: Vec<Vec<String>>,
     }
 }
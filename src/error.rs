@@ -0,0 +1,156 @@
+use crate::html_parser::Rule;
+use pest::error::Error as PestError;
+use pest::iterators::Pair;
+use std::fmt::{self, Display};
+use std::io;
+use std::path::PathBuf;
+
+/// Where in the audit a value failed to parse, and what it was.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    /// The offending text
+    pub text: String,
+    /// 1-based line of the start of `text`
+    pub line: usize,
+    /// 1-based column of the start of `text`
+    pub column: usize,
+}
+
+impl From<&Pair<'_, Rule>> for Location {
+    fn from(pair: &Pair<'_, Rule>) -> Self {
+        let (line, column) = pair.as_span().start_pos().line_col();
+        Self {
+            text: pair.as_str().to_string(),
+            line,
+            column,
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} at {}:{}", self.text, self.line, self.column)
+    }
+}
+
+/// Everything that can go wrong while parsing an audit.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuditError {
+    /// The audit does not match `audit.pest`
+    Grammar(Box<PestError<Rule>>),
+    /// `GRADUATION DATE:` is not a `mm/dd/yy` date
+    Date(Location),
+    /// `PREPARED:` is not a `mm/dd/yy - hh:mm` run date
+    RunDate(Location),
+    /// `CATALOG YEAR:` is not two consecutive years, i.e. 202021
+    CatalogYear(Location),
+    /// Not one of the NUPath abbreviations
+    NUPath(Location),
+    /// Not one of the Season abbreviations
+    Season(Location),
+    /// Not one of `OK`, `IP` or `NO`
+    Status(Location),
+    /// Not a grade the registrar issues
+    Grade(Location),
+    /// Credits, hours, course numbers etc. that do not fit their numeric type
+    Number(Location),
+    /// A rule matched but did not contain what the grammar promises
+    Unexpected(Location),
+}
+
+impl AuditError {
+    /// Location of the offending text, if the error came from a matched rule.
+    pub fn location(&self) -> Option<&Location> {
+        use AuditError::*;
+        match self {
+            Grammar(_) => None,
+            Date(loc) | RunDate(loc) | CatalogYear(loc) | NUPath(loc) | Season(loc)
+            | Status(loc) | Grade(loc) | Number(loc) | Unexpected(loc) => Some(loc),
+        }
+    }
+}
+
+impl Display for AuditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use AuditError::*;
+        match self {
+            Grammar(err) => write!(f, "audit does not match grammar\n{}", err),
+            Date(loc) => write!(f, "invalid date {}", loc),
+            RunDate(loc) => write!(f, "invalid run date {}", loc),
+            CatalogYear(loc) => write!(f, "invalid catalog year {}", loc),
+            NUPath(loc) => write!(f, "unknown NUPath {}", loc),
+            Season(loc) => write!(f, "unknown season {}", loc),
+            Status(loc) => write!(f, "unknown status {}", loc),
+            Grade(loc) => write!(f, "unknown grade {}", loc),
+            Number(loc) => write!(f, "invalid number {}", loc),
+            Unexpected(loc) => write!(f, "unexpected structure {}", loc),
+        }
+    }
+}
+
+impl std::error::Error for AuditError {}
+
+impl From<PestError<Rule>> for AuditError {
+    fn from(err: PestError<Rule>) -> Self {
+        AuditError::Grammar(Box::new(err))
+    }
+}
+
+/// Everything that can go wrong while loading major definitions.
+#[derive(Debug)]
+pub enum MajorError {
+    /// The file or directory could not be read
+    Io(PathBuf, io::Error),
+    /// The file is not json, or does not match [`Major`](crate::constants::majors::Major)
+    Schema {
+        /// File the definition was read from
+        file: PathBuf,
+        /// Json path of the offending value, i.e. `requirement_group_map.Core.And.name`
+        path: String,
+        /// What was wrong with it
+        message: String,
+    },
+    /// The json matches but the definition contradicts itself
+    Invalid {
+        /// File the definition was read from
+        file: PathBuf,
+        /// Json path of the offending value
+        path: String,
+        /// What was wrong with it
+        message: String,
+    },
+}
+
+impl Display for MajorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use MajorError::*;
+        match self {
+            Io(file, err) => write!(f, "cannot read {}: {}", file.display(), err),
+            Schema {
+                file,
+                path,
+                message,
+            } => write!(f, "{}: bad schema at {}: {}", file.display(), path, message),
+            Invalid {
+                file,
+                path,
+                message,
+            } => write!(
+                f,
+                "{}: invalid major at {}: {}",
+                file.display(),
+                path,
+                message
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MajorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MajorError::Io(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,529 @@
+//! Degree requirement evaluation, independent of the registrar's audit.
+use crate::constants::{
+    courses::{CourseRange, Requirement},
+    majors::{Major, MajorRequirement, Section, SectionRange},
+    parser_types::CompleteCourse,
+};
+use serde::{Deserialize, Serialize};
+
+/// How far a requirement group is from being satisfied
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
+pub enum Progress {
+    /// Satisfied by completed courses
+    Satisfied,
+    /// Satisfied once the in progress courses are completed
+    InProgress,
+    /// Some, but not enough, courses apply
+    Partial,
+    /// No course applies
+    Unsatisfied,
+}
+
+/// Outcome of one requirement group of a major
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupEvaluation {
+    /// i.e. "Computer Science Fundamental Courses"
+    pub name: String,
+    /// How far the group is from being satisfied
+    pub progress: Progress,
+    /// Credits of the applied courses
+    pub credits: f32,
+    /// Courses applied to the group
+    pub courses: Vec<CompleteCourse>,
+}
+
+/// Outcome of every requirement group of a major
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Evaluation {
+    /// i.e. "Computer Science, BSCS"
+    pub major: String,
+    /// Requirement groups, in catalog order
+    pub groups: Vec<GroupEvaluation>,
+}
+
+impl Evaluation {
+    /// True if every requirement group is satisfied by completed courses.
+    pub fn is_satisfied(&self) -> bool {
+        self.groups
+            .iter()
+            .all(|group| group.progress == Progress::Satisfied)
+    }
+
+    /// Groups that are not yet satisfied, counting in progress courses as completed.
+    pub fn remaining(&self) -> impl Iterator<Item = &GroupEvaluation> {
+        self.groups.iter().filter(|group| {
+            group.progress == Progress::Partial || group.progress == Progress::Unsatisfied
+        })
+    }
+}
+
+/// Evaluate every requirement group of `major` against the courses a student has taken.
+///
+/// A course is applied to at most one group, the first in catalog order that uses it,
+/// however often it is listed. Failed, withdrawn, repeated and excluded courses are
+/// never applied.
+pub fn evaluate(
+    major: &Major,
+    completed: &[CompleteCourse],
+    in_progress: &[CompleteCourse],
+) -> Evaluation {
+    evaluate_with_concentrations(major, &[], completed, in_progress)
+}
+
+/// [`evaluate`](evaluate), followed by the groups of the declared `concentrations`.
+///
+/// A major that requires a concentration gets an unsatisfied "Concentration" group when
+/// none of its concentrations is declared.
+pub fn evaluate_with_concentrations(
+    major: &Major,
+    concentrations: &[&str],
+    completed: &[CompleteCourse],
+    in_progress: &[CompleteCourse],
+) -> Evaluation {
+    let mut pool = Pool::new(completed, in_progress);
+    let mut groups: Vec<_> = major
+        .requirement_groups
+        .iter()
+        .filter_map(|name| {
+            let requirement = major.requirement_group_map.get(name)?;
+            Some(pool.evaluate_group(name, requirement))
+        })
+        .collect();
+    if let Some(offered) = &major.concentrations {
+        let chosen: Vec<_> = offered
+            .requirements_group_map
+            .iter()
+            .filter(|group| {
+                concentrations
+                    .iter()
+                    .any(|concentration| group.is_concentration(concentration))
+            })
+            .collect();
+        if chosen.is_empty() && offered.min_options > 0 {
+            groups.push(GroupEvaluation {
+                name: String::from("Concentration"),
+                progress: Progress::Unsatisfied,
+                credits: 0_f32,
+                courses: vec![],
+            });
+        }
+        for group in chosen {
+            groups.push(pool.evaluate_group(group.name(), group));
+        }
+    }
+    Evaluation {
+        major: major.name.clone(),
+        groups,
+    }
+}
+
+/// Result of matching a requirement: whether it is met and the courses claimed for it
+#[derive(Debug, Default)]
+struct Match {
+    satisfied: bool,
+    claimed: Vec<usize>,
+}
+
+/// Courses available to requirements, and which of them are already applied
+#[derive(Debug)]
+struct Pool<'c> {
+    /// Course and whether it is in progress
+    courses: Vec<(&'c CompleteCourse, bool)>,
+    used: Vec<bool>,
+}
+
+impl<'c> Pool<'c> {
+    fn new(completed: &'c [CompleteCourse], in_progress: &'c [CompleteCourse]) -> Self {
+        let mut courses: Vec<(&CompleteCourse, bool)> = vec![];
+        let available = completed
+            .iter()
+            .map(|course| (course, false))
+            .chain(in_progress.iter().map(|course| (course, true)))
+            .filter(|(course, _)| {
+                course.counts() && !course.grade.is_some_and(|grade| grade.is_failing())
+            });
+        for (course, in_progress) in available {
+            if !courses
+                .iter()
+                .any(|(taken, _)| taken.is_same_attempt(course))
+            {
+                courses.push((course, in_progress));
+            }
+        }
+        let used = vec![false; courses.len()];
+        Self { courses, used }
+    }
+
+    fn credits(&self, claimed: &[usize]) -> f32 {
+        claimed
+            .iter()
+            .map(|&i| self.courses[i].0.credit_hours)
+            .sum()
+    }
+
+    fn release(&mut self, claimed: &[usize]) {
+        claimed.iter().for_each(|&i| self.used[i] = false);
+    }
+
+    /// Release the courses claimed once `max` credits are reached, for later groups.
+    fn cap(&mut self, claimed: &mut Vec<usize>, max: Option<isize>) {
+        let max = match max {
+            Some(max) => max as f32,
+            None => return,
+        };
+        let mut credits = 0_f32;
+        let kept = claimed
+            .iter()
+            .take_while(|&&i| {
+                let is_under = credits < max;
+                credits += self.courses[i].0.credit_hours;
+                is_under
+            })
+            .count();
+        let released = claimed.split_off(kept);
+        self.release(&released);
+    }
+
+    /// Claim the first unused course matching `pred`.
+    fn claim(&mut self, pred: impl Fn(&CompleteCourse) -> bool) -> Option<usize> {
+        let index = (0..self.courses.len()).find(|&i| !self.used[i] && pred(self.courses[i].0))?;
+        self.used[index] = true;
+        Some(index)
+    }
+
+    fn evaluate_group(&mut self, name: &str, requirement: &MajorRequirement) -> GroupEvaluation {
+        let mut found = match requirement {
+            MajorRequirement::And(section) => self.and_section(section),
+            MajorRequirement::Or(section) => self.or_section(section),
+            MajorRequirement::Range(range) => self.range_section(range),
+        };
+        // Ranges stop at `num_credits_max` as they claim courses
+        if let MajorRequirement::And(section) | MajorRequirement::Or(section) = requirement {
+            self.cap(&mut found.claimed, section.num_credits_max);
+        }
+        let in_progress = found.claimed.iter().any(|&i| self.courses[i].1);
+        let progress = match (found.satisfied, found.claimed.is_empty()) {
+            (true, _) if in_progress => Progress::InProgress,
+            (true, _) => Progress::Satisfied,
+            (false, false) => Progress::Partial,
+            (false, true) => Progress::Unsatisfied,
+        };
+        GroupEvaluation {
+            name: name.to_string(),
+            progress,
+            credits: self.credits(&found.claimed),
+            courses: found
+                .claimed
+                .iter()
+                .map(|&i| self.courses[i].0.clone())
+                .collect(),
+        }
+    }
+
+    /// Every requirement, and at least `num_credits_min` credits. Credits past
+    /// `num_credits_max` are capped by the caller.
+    fn and_section(&mut self, section: &Section) -> Match {
+        let mut found = Match {
+            satisfied: true,
+            claimed: vec![],
+        };
+        for requirement in &section.requirements {
+            let inner = self.requirement(requirement);
+            found.satisfied &= inner.satisfied;
+            found.claimed.extend(inner.claimed);
+        }
+        found.satisfied &= Self::enough(self.credits(&found.claimed), section.num_credits_min);
+        found
+    }
+
+    /// `num_credits_min` credits across the requirements, or `num_courses` of the
+    /// requirements, or any one requirement if both are unset.
+    fn or_section(&mut self, section: &Section) -> Match {
+        let mut found = Match::default();
+        if let Some(count) = section.num_courses.filter(|&count| count > 1) {
+            let mut satisfied = 0;
+            for requirement in &section.requirements {
+                if satisfied >= count {
+                    break;
+                }
+                let inner = self.requirement(requirement);
+                if inner.satisfied {
+                    satisfied += 1;
+                    found.claimed.extend(inner.claimed);
+                } else {
+                    self.release(&inner.claimed);
+                }
+            }
+            found.satisfied = satisfied >= count;
+            return found;
+        }
+        if let Some(min) = section.num_credits_min {
+            for requirement in &section.requirements {
+                if Self::enough(self.credits(&found.claimed), Some(min)) {
+                    break;
+                }
+                let inner = self.requirement(requirement);
+                found.claimed.extend(inner.claimed);
+            }
+            found.satisfied = Self::enough(self.credits(&found.claimed), Some(min));
+            return found;
+        }
+        for requirement in &section.requirements {
+            let inner = self.requirement(requirement);
+            if inner.satisfied {
+                self.release(&found.claimed);
+                return inner;
+            }
+            // Keep the best partial match in case no requirement is satisfied
+            if inner.claimed.len() > found.claimed.len() {
+                self.release(&found.claimed);
+                found = inner;
+            } else {
+                self.release(&inner.claimed);
+            }
+        }
+        found
+    }
+
+    /// Between `num_credits_min` and `num_credits_max` credits from the ranges.
+    fn range_section(&mut self, section: &SectionRange) -> Match {
+        let mut found = Match::default();
+        let cap = section.num_credits_max.or(section.num_credits_min);
+        for range in &section.requirements {
+            let left = cap.map(|cap| cap - self.credits(&found.claimed) as isize);
+            found.claimed.extend(self.course_range(range, left).claimed);
+        }
+        found.satisfied = Self::enough(self.credits(&found.claimed), section.num_credits_min);
+        found
+    }
+
+    fn requirement(&mut self, requirement: &Requirement) -> Match {
+        match requirement {
+            Requirement::RequiredCourse(required) => {
+                let claimed: Vec<_> = self
+                    .claim(|course| {
+                        course.subject == required.subject && course.class_id == required.class_id
+                    })
+                    .into_iter()
+                    .collect();
+                Match {
+                    satisfied: !claimed.is_empty(),
+                    claimed,
+                }
+            }
+            Requirement::AndCourse(requirements) => {
+                let mut found = Match {
+                    satisfied: true,
+                    claimed: vec![],
+                };
+                for requirement in requirements {
+                    let inner = self.requirement(requirement);
+                    found.satisfied &= inner.satisfied;
+                    found.claimed.extend(inner.claimed);
+                }
+                found
+            }
+            Requirement::OrCourse(requirements) => {
+                let mut found = Match::default();
+                for requirement in requirements {
+                    let inner = self.requirement(requirement);
+                    if inner.satisfied {
+                        self.release(&found.claimed);
+                        return inner;
+                    }
+                    if found.claimed.is_empty() {
+                        found = inner;
+                    } else {
+                        self.release(&inner.claimed);
+                    }
+                }
+                found
+            }
+            Requirement::CourseRange(range) => {
+                self.course_range(range, Some(range.credits_required))
+            }
+        }
+    }
+
+    /// Claim courses of the range until `cap` credits, or every matching course if unset.
+    fn course_range(&mut self, range: &CourseRange, cap: Option<isize>) -> Match {
+        let mut found = Match::default();
+        while !cap.is_some_and(|cap| self.credits(&found.claimed) >= cap as f32) {
+            let next = self.claim(|course| {
+                range.ranges.iter().any(|subject| {
+                    course.subject == subject.subject
+                        && (subject.id_range_start..=subject.id_range_end)
+                            .contains(&course.class_id)
+                })
+            });
+            match next {
+                Some(index) => found.claimed.push(index),
+                None => break,
+            }
+        }
+        found.satisfied = Self::enough(self.credits(&found.claimed), Some(range.credits_required));
+        found
+    }
+
+    fn enough(credits: f32, min: Option<isize>) -> bool {
+        min.is_none_or(|min| credits >= min as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{
+        abbreviations::{Grade, Season},
+        courses::{Course, SubjectRange},
+        terms::TermId,
+    };
+    use std::collections::HashMap;
+
+    fn course(subject: &str, class_id: isize, grade: Option<Grade>) -> CompleteCourse {
+        CompleteCourse {
+            credit_hours: 4.0,
+            grade,
+            ..CompleteCourse::new(subject.into(), class_id, TermId::new(Season::FL, 2021))
+        }
+    }
+
+    fn required(subject: &str, class_id: isize) -> Requirement {
+        Requirement::RequiredCourse(Course {
+            class_id,
+            subject: subject.into(),
+            is_required: true,
+        })
+    }
+
+    fn major() -> Major {
+        let groups = vec![
+            (
+                "Fundamentals",
+                MajorRequirement::And(Section {
+                    requirements: vec![required("CS", 2500), required("CS", 2510)],
+                    name: "Fundamentals".into(),
+                    ..Default::default()
+                }),
+            ),
+            (
+                "Statistics",
+                MajorRequirement::Or(Section {
+                    requirements: vec![required("MATH", 3081), required("CS", 3081)],
+                    name: "Statistics".into(),
+                    ..Default::default()
+                }),
+            ),
+            (
+                "Electives",
+                MajorRequirement::Range(SectionRange {
+                    requirements: vec![CourseRange {
+                        credits_required: 8,
+                        ranges: vec![SubjectRange {
+                            subject: "CS".into(),
+                            id_range_start: 2500,
+                            id_range_end: 5999,
+                        }],
+                    }],
+                    num_credits_min: Some(8),
+                    num_credits_max: Some(8),
+                    name: "Electives".into(),
+                }),
+            ),
+        ];
+        Major {
+            name: "Computer Science, BSCS".into(),
+            requirement_groups: groups.iter().map(|(name, _)| name.to_string()).collect(),
+            requirement_group_map: groups
+                .into_iter()
+                .map(|(name, group)| (name.to_string(), group))
+                .collect::<HashMap<_, _>>(),
+            year_version: 2020,
+            is_language_required: false,
+            total_credits_required: 134,
+            nu_paths: vec![],
+            concentrations: None,
+        }
+    }
+
+    #[test]
+    fn evaluates_groups() {
+        let completed = vec![
+            course("CS", 2500, Some(Grade::A)),
+            course("CS", 2510, Some(Grade::F)),
+            course("CS", 3500, Some(Grade::B)),
+            course("MATH", 3081, Some(Grade::A)),
+        ];
+        let in_progress = vec![course("CS", 4500, None)];
+
+        let evaluation = evaluate(&major(), &completed, &in_progress);
+        let progress: Vec<_> = evaluation.groups.iter().map(|g| g.progress).collect();
+        assert_eq!(
+            progress,
+            vec![Progress::Partial, Progress::Satisfied, Progress::InProgress]
+        );
+        assert!(!evaluation.is_satisfied());
+        assert_eq!(evaluation.remaining().count(), 1);
+        // CS 2500 counts towards the fundamentals, not the electives
+        let electives = &evaluation.groups[2];
+        assert_eq!(electives.credits, 8.0);
+        assert_eq!(electives.courses[0].class_id, 3500);
+    }
+
+    #[test]
+    fn unmatched_groups_are_unsatisfied() {
+        let evaluation = evaluate(&major(), &[], &[]);
+        assert!(evaluation
+            .groups
+            .iter()
+            .all(|group| group.progress == Progress::Unsatisfied && group.courses.is_empty()));
+    }
+
+    #[test]
+    fn applies_a_listed_twice_course_once() {
+        let mut major = major();
+        let fundamentals = major.requirement_group_map["Fundamentals"].clone();
+        major.requirement_groups.push("Fundamentals Again".into());
+        major
+            .requirement_group_map
+            .insert("Fundamentals Again".into(), fundamentals);
+        // Listed under its NUPath and again under its requirement block
+        let completed = vec![
+            course("CS", 2500, Some(Grade::A)),
+            course("CS", 2510, Some(Grade::A)),
+            course("CS", 2500, Some(Grade::A)),
+        ];
+
+        let evaluation = evaluate(&major, &completed, &[]);
+        assert_eq!(evaluation.groups[0].progress, Progress::Satisfied);
+        assert_eq!(evaluation.groups[3].progress, Progress::Unsatisfied);
+    }
+
+    #[test]
+    fn caps_credits_at_the_max() {
+        let mut major = major();
+        for (name, max) in &[("Fundamentals", 4), ("Statistics", 4)] {
+            match major.requirement_group_map.get_mut(*name) {
+                Some(MajorRequirement::And(section)) | Some(MajorRequirement::Or(section)) => {
+                    section.num_credits_max = Some(*max);
+                    section.num_courses = Some(2);
+                }
+                _ => panic!("no section {}", name),
+            }
+        }
+        let completed = vec![
+            course("CS", 2500, Some(Grade::A)),
+            course("CS", 2510, Some(Grade::A)),
+            course("MATH", 3081, Some(Grade::A)),
+            course("CS", 3081, Some(Grade::A)),
+            course("CS", 3500, Some(Grade::B)),
+        ];
+
+        let evaluation = evaluate(&major, &completed, &[]);
+        let credits: Vec<_> = evaluation.groups.iter().map(|g| g.credits).collect();
+        assert_eq!(credits, vec![4.0, 4.0, 8.0]);
+        // CS 2510 is past the cap of the fundamentals, so the electives can use it
+        let electives = &evaluation.groups[2];
+        assert_eq!(electives.courses[0].class_id, 2510);
+    }
+}
@@ -1,23 +1,43 @@
+use crate::constants::schedule::{
+    self, Schedule, ScheduleCourse, ScheduleTerm, ScheduleYear, Term,
+};
+use crate::constants::terms::TermId;
+use crate::constants::tracking::{CourseTracker, UserData};
 use crate::constants::{
-    abbreviations::{NUPath, Season, Status},
-    parser_types::{CompleteCourse, Requirement},
+    abbreviations::{Grade, NUPath, Season, Status},
+    courses::{self, Course, CourseRange, SubjectRange},
+    majors::{Major, MajorRequirement, Section},
+    parser_types::{
+        self, AuditHeader, AuditSection, CatalogYear, CompleteCourse, Counter, CreditSource, Data,
+        InitialScheduleRep, NUPathResult, Requirement, Requirements, TransferCredit,
+    },
 };
-use chrono::{NaiveDate, Utc};
+use crate::evaluation::{self, Evaluation};
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 
+/// A parsed Web Audit
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AuditToJson<'a> {
+pub struct Audit<'a> {
+    program: Option<Cow<'a, str>>,
     majors: Vec<Cow<'a, str>>,
     minors: Vec<Cow<'a, str>>,
+    concentrations: Vec<Cow<'a, str>>,
     audit_year: isize,
+    catalog_year: CatalogYear,
+    header: AuditHeader,
     grad_date: NaiveDate,
     complete_nupaths: Vec<NUPath>,
     complete_courses: Vec<CompleteCourse>,
     ip_nupaths: Vec<NUPath>,
     ip_courses: Vec<CompleteCourse>,
     required_nupaths: Vec<NUPath>,
+    nupaths: BTreeMap<NUPath, NUPathResult>,
     required_courses: Vec<Requirement>,
+    sections: Vec<AuditSection>,
+    transfer_credits: Vec<TransferCredit>,
     earned_hours: f32,
     courses_taken: isize,
     attempted_hours: f32,
@@ -25,19 +45,26 @@ pub struct AuditToJson<'a> {
     gpa: f32,
 }
 
-impl AuditToJson<'_> {
-    pub fn new() -> Self {
+impl<'a> Audit<'a> {
+    pub(crate) fn new() -> Self {
         Self {
+            program: None,
             majors: vec![],
             minors: vec![],
+            concentrations: vec![],
             audit_year: 2020,
-            grad_date: NaiveDate::from_ymd(2020, 1, 1),
+            catalog_year: CatalogYear::default(),
+            header: AuditHeader::default(),
+            grad_date: NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
             complete_courses: vec![],
             complete_nupaths: vec![],
             ip_courses: vec![],
             ip_nupaths: vec![],
             required_courses: vec![],
             required_nupaths: vec![],
+            nupaths: BTreeMap::new(),
+            sections: vec![],
+            transfer_credits: vec![],
             earned_hours: 0_f32,
             courses_taken: 0,
             attempted_hours: 0_f32,
@@ -45,262 +72,1620 @@ impl AuditToJson<'_> {
             gpa: 0_f32,
         }
     }
+
+    /// Program title above the majors, i.e. "BS Computer Science and Design"
+    pub fn program(&self) -> Option<&str> {
+        self.program.as_deref()
+    }
+
+    /// Declared majors, i.e. "Computer Science"
+    pub fn majors(&self) -> &[Cow<'a, str>] {
+        &self.majors
+    }
+
+    /// Declared minors, i.e. "Mathematics"
+    pub fn minors(&self) -> &[Cow<'a, str>] {
+        &self.minors
+    }
+
+    /// Declared concentrations, i.e. "Software"
+    pub fn concentrations(&self) -> &[Cow<'a, str>] {
+        &self.concentrations
+    }
+
+    /// Requirement blocks of the declared concentrations
+    pub fn concentration_requirements(&self) -> Vec<&MajorRequirement> {
+        self.sections
+            .iter()
+            .map(|section| &section.requirement)
+            .filter(|requirement| {
+                self.concentrations
+                    .iter()
+                    .any(|concentration| requirement.is_concentration(concentration))
+            })
+            .collect()
+    }
+
+    /// Year the catalog starts in, i.e. 2020 for `CATALOG YEAR: 202021`
+    pub fn audit_year(&self) -> isize {
+        self.audit_year
+    }
+
+    /// `CATALOG YEAR:`, i.e. 2020 to 2021
+    pub fn catalog_year(&self) -> CatalogYear {
+        self.catalog_year
+    }
+
+    /// Student, college and advisor the audit was run for
+    pub fn header(&self) -> &AuditHeader {
+        &self.header
+    }
+
+    /// Expected graduation date
+    pub fn grad_date(&self) -> NaiveDate {
+        self.grad_date
+    }
+
+    /// NUPaths the audit marks `OK`
+    pub fn complete_nupaths(&self) -> &[NUPath] {
+        &self.complete_nupaths
+    }
+
+    /// Courses with a final grade
+    pub fn complete_courses(&self) -> &[CompleteCourse] {
+        &self.complete_courses
+    }
+
+    /// NUPaths the audit marks `IP`
+    pub fn ip_nupaths(&self) -> &[NUPath] {
+        &self.ip_nupaths
+    }
+
+    /// Courses without a final grade yet
+    pub fn ip_courses(&self) -> &[CompleteCourse] {
+        &self.ip_courses
+    }
+
+    /// NUPaths the audit marks `NO`
+    pub fn required_nupaths(&self) -> &[NUPath] {
+        &self.required_nupaths
+    }
+
+    /// Every NUPath with its name, status and satisfying courses
+    pub fn nupaths(&self) -> &BTreeMap<NUPath, NUPathResult> {
+        &self.nupaths
+    }
+
+    /// Every `Course List:` entry of the audit
+    pub fn required_courses(&self) -> &[Requirement] {
+        &self.required_courses
+    }
+
+    /// Requirement blocks of the audit
+    pub fn sections(&self) -> &[AuditSection] {
+        &self.sections
+    }
+
+    /// Transfer, AP, IB and exam credit
+    pub fn transfer_credits(&self) -> &[TransferCredit] {
+        &self.transfer_credits
+    }
+
+    /// Credits awarded for transfer, AP, IB and exams
+    pub fn transfer_hours(&self) -> f32 {
+        self.transfer_credits
+            .iter()
+            .map(|credit| credit.credit_hours)
+            .sum()
+    }
+
+    /// Credits earned, transfer credit included
+    pub fn earned_hours(&self) -> f32 {
+        self.earned_hours
+    }
+
+    /// Courses taken at Northeastern
+    pub fn courses_taken(&self) -> isize {
+        self.courses_taken
+    }
+
+    /// Credits attempted for a grade
+    pub fn attempted_hours(&self) -> f32 {
+        self.attempted_hours
+    }
+
+    /// Quality points earned
+    pub fn points(&self) -> f32 {
+        self.points
+    }
+
+    /// Cumulative GPA
+    pub fn gpa(&self) -> f32 {
+        self.gpa
+    }
+
+    /// Detach from the source text the audit was parsed from.
+    pub fn into_owned(self) -> Audit<'static> {
+        Audit {
+            program: self.program.map(|program| Cow::Owned(program.into_owned())),
+            majors: self
+                .majors
+                .into_iter()
+                .map(|major| Cow::Owned(major.into_owned()))
+                .collect(),
+            minors: self
+                .minors
+                .into_iter()
+                .map(|minor| Cow::Owned(minor.into_owned()))
+                .collect(),
+            concentrations: self
+                .concentrations
+                .into_iter()
+                .map(|concentration| Cow::Owned(concentration.into_owned()))
+                .collect(),
+            audit_year: self.audit_year,
+            catalog_year: self.catalog_year,
+            header: self.header,
+            grad_date: self.grad_date,
+            complete_nupaths: self.complete_nupaths,
+            complete_courses: self.complete_courses,
+            ip_nupaths: self.ip_nupaths,
+            ip_courses: self.ip_courses,
+            required_nupaths: self.required_nupaths,
+            nupaths: self.nupaths,
+            required_courses: self.required_courses,
+            sections: self.sections,
+            transfer_credits: self.transfer_credits,
+            earned_hours: self.earned_hours,
+            courses_taken: self.courses_taken,
+            attempted_hours: self.attempted_hours,
+            points: self.points,
+            gpa: self.gpa,
+        }
+    }
+
+    /// Term the audit was run in, from `PREPARED:` or else the latest term with a course
+    /// in progress.
+    fn run_term(&self) -> Option<TermId> {
+        match self.header.prepared {
+            Some(run) => Some(TermId::containing(run.date())),
+            None => self.ip_courses.iter().map(|course| course.term_id).max(),
+        }
+    }
+
+    /// Move the courses registered for a later term than the audit was run in, or not
+    /// graded yet in that term, to the in progress courses.
+    fn defer_unfinished(&mut self) {
+        let run_term = match self.run_term() {
+            Some(run_term) => run_term,
+            None => return,
+        };
+        let (unfinished, complete): (Vec<_>, Vec<_>) = std::mem::take(&mut self.complete_courses)
+            .into_iter()
+            .partition(|course| {
+                course.term_id > run_term || (course.term_id == run_term && course.grade.is_none())
+            });
+        self.complete_courses = complete;
+        self.ip_courses.extend(unfinished);
+    }
+
+    /// The innermost section the audit is currently listing requirements for.
+    fn current_section(&mut self) -> Option<&mut AuditSection> {
+        let section = self.sections.last_mut()?;
+        if section.sections.is_empty() {
+            return Some(section);
+        }
+        section.sections.last_mut()
+    }
+
+    /// Evaluate `major`'s requirement groups against the courses and transfer credit of
+    /// the audit.
+    pub fn evaluate(&self, major: &Major) -> Evaluation {
+        let mut completed = self.complete_courses.clone();
+        // Credit held on entry, the summer before the first fall of the catalog year
+        let entry_term = TermId::new(Season::FL, self.catalog_year.end).previous();
+        completed.extend(
+            self.transfer_credits
+                .iter()
+                .map(|credit| credit.to_course(entry_term)),
+        );
+        let concentrations: Vec<&str> = self.concentrations.iter().map(AsRef::as_ref).collect();
+        evaluation::evaluate_with_concentrations(
+            major,
+            &concentrations,
+            &completed,
+            &self.ip_courses,
+        )
+    }
+
+    /// GPA of the graded, completed courses in each term, keyed by term id. Repeated,
+    /// withdrawn and excluded attempts are left out.
+    pub fn gpa_by_term(&self) -> BTreeMap<TermId, f32> {
+        let mut totals: BTreeMap<TermId, (f32, f32)> = BTreeMap::new();
+        for course in self.complete_courses.iter().filter(|c| c.counts()) {
+            if let Some(points) = course.grade.and_then(Grade::quality_points) {
+                let (quality, hours) = totals.entry(course.term_id).or_default();
+                *quality += points * course.credit_hours;
+                *hours += course.credit_hours;
+            }
+        }
+        totals
+            .into_iter()
+            .filter(|(_, (_, hours))| *hours > 0_f32)
+            .map(|(term_id, (quality, hours))| (term_id, quality / hours))
+            .collect()
+    }
+}
+
+/// GraduateNU's format, so its frontend can consume this parser's output unchanged.
+impl From<&Audit<'_>> for InitialScheduleRep {
+    fn from(audit: &Audit<'_>) -> Self {
+        let courses = |nupaths: &[NUPath], courses: &[CompleteCourse]| parser_types::Course {
+            nupaths: nupaths.to_vec(),
+            courses: courses.iter().map(Into::into).collect(),
+        };
+        Self {
+            completed: courses(&audit.complete_nupaths, &audit.complete_courses),
+            in_progress: courses(&audit.ip_nupaths, &audit.ip_courses),
+            requirements: Requirements {
+                nupaths: audit.required_nupaths.clone(),
+                courses: audit.required_courses.iter().map(Into::into).collect(),
+            },
+            data: Data {
+                majors: audit.majors.iter().map(|major| major.to_string()).collect(),
+                minors: audit.minors.iter().map(|minor| minor.to_string()).collect(),
+                audit_year: audit.audit_year,
+                grad_date: audit.grad_date,
+            },
+        }
+    }
+}
+
+/// The student's history, every course in the term it was taken. Terms without
+/// classes are `INACTIVE`, or `COOP` when the only course is a co-op.
+impl From<&Audit<'_>> for Schedule {
+    fn from(audit: &Audit<'_>) -> Self {
+        let mut terms: BTreeMap<TermId, Vec<&CompleteCourse>> = BTreeMap::new();
+        for course in audit.complete_courses.iter().chain(&audit.ip_courses) {
+            let classes = terms.entry(course.term_id).or_default();
+            if !classes.contains(&course) {
+                classes.push(course);
+            }
+        }
+        let first = terms
+            .keys()
+            .next()
+            .map_or(0, |term_id| term_id.academic_year());
+        let last = terms
+            .keys()
+            .next_back()
+            .map_or(-1, |term_id| term_id.academic_year());
+        let mut id = 0;
+        let mut term = |term_id: TermId| {
+            let courses = terms.get(&term_id).map_or(&[][..], Vec::as_slice);
+            let is_coop = |course: &&CompleteCourse| course.subject == "COOP";
+            let status = match courses {
+                [] => schedule::Status::INACTIVE,
+                _ if courses.iter().all(is_coop) => schedule::Status::COOP,
+                _ => schedule::Status::CLASSES,
+            };
+            id += 1;
+            ScheduleTerm::Term(Term {
+                season: term_id.season(),
+                year: term_id.calendar_year() % 100,
+                term_id,
+                id,
+                status,
+                classes: courses
+                    .iter()
+                    .filter(|course| !is_coop(course))
+                    .map(|course| ScheduleCourse {
+                        name: course.name.clone(),
+                        class_id: course.class_id.to_string(),
+                        subject: course.subject.clone(),
+                        pre_reqs: None,
+                        co_reqs: None,
+                        num_credits_min: course.credit_hours as isize,
+                        num_credits_max: course.credit_hours as isize,
+                    })
+                    .collect(),
+            })
+        };
+        let years: Vec<isize> = (first..=last).collect();
+        let year_map = years
+            .iter()
+            .map(|&year| {
+                let is_summer_full = terms.contains_key(&TermId::new(Season::SM, year));
+                let summer1 = if is_summer_full {
+                    Season::SM
+                } else {
+                    Season::S1
+                };
+                let schedule_year = ScheduleYear {
+                    year,
+                    fall: term(TermId::new(Season::FL, year)),
+                    spring: term(TermId::new(Season::SP, year)),
+                    summer1: term(TermId::new(summer1, year)),
+                    summer2: term(TermId::new(Season::S2, year)),
+                    is_summer_full,
+                };
+                (year, schedule_year)
+            })
+            .collect();
+        Self {
+            years,
+            year_map,
+            id: String::from("0"),
+        }
+    }
+}
+
+/// Every completed and in progress course of the audit, withdrawals excluded.
+impl From<&Audit<'_>> for CourseTracker {
+    fn from(audit: &Audit<'_>) -> Self {
+        let mut tracker = CourseTracker::new();
+        audit
+            .complete_courses
+            .iter()
+            .chain(&audit.ip_courses)
+            .filter(|course| !course.withdrawn)
+            .for_each(|course| tracker.add(&course.subject, course.class_id, course.term_id));
+        tracker
+    }
+}
+
+/// The student of the audit and their history. The major definition is left for the
+/// caller to look up.
+impl From<&Audit<'_>> for UserData {
+    fn from(audit: &Audit<'_>) -> Self {
+        Self {
+            full_name: audit.header.full_name(),
+            academic_year: None,
+            graduation_year: Some(audit.grad_date.year() as isize),
+            major: None,
+            minors: Some(audit.minors.iter().map(|minor| minor.to_string()).collect()),
+            plan: Some(audit.into()),
+        }
+    }
 }
 
-use pest::error::Error as PestError;
+use crate::error::{AuditError, Location};
+use crate::html_text::strip_html;
 use pest::iterators::Pair;
 use pest::Parser;
 use std::convert::TryInto;
 
-#[derive(Parser)]
-#[grammar = "audit.pest"]
+#[allow(missing_docs)]
+mod grammar {
+    #[derive(Parser)]
+    #[grammar = "audit.pest"]
+    pub struct AuditGrammar;
+}
+
+use grammar::AuditGrammar;
+/// Rules of `audit.pest`, as reported by [`AuditError::Grammar`](crate::AuditError::Grammar)
+pub use grammar::Rule;
+
+/// Parses NEU Web Audits
+#[derive(Debug)]
 pub struct AuditParser;
 
 impl AuditParser {
-    pub fn parse_audit(file: &'_ str) -> Result<AuditToJson<'_>, PestError<Rule>> {
-        let main = Self::parse(Rule::main, file)?.next().unwrap();
-        let mut out = AuditToJson::new();
-        fn parse_inner<'a>(mut out: &mut AuditToJson<'a>, rule: Pair<'a, Rule>) {
+    /// Parse a Web Audit stripped to text, borrowing from `file` where possible.
+    pub fn parse_audit(file: &'_ str) -> Result<Audit<'_>, AuditError> {
+        let main = Self::first_inner(AuditGrammar::parse(Rule::main, file)?)?;
+        let mut out = Audit::new();
+        /// Where the parser is in the audit, rather than what the audit says
+        #[derive(Default)]
+        struct Context {
+            /// NUPath whose satisfying courses are being listed
+            current_nupath: Option<NUPath>,
+        }
+        fn parse_inner<'a>(
+            out: &mut Audit<'a>,
+            context: &mut Context,
+            rule: Pair<'a, Rule>,
+        ) -> Result<(), AuditError> {
             match rule.as_rule() {
                 Rule::GRAD_PARSER => {
-                    let date = rule
-                        .into_inner()
-                        .next() // Move in to GRAD_PARSER Steps
-                        .unwrap()
-                        .into_inner()
-                        .next() // Skip GRAD_STRING
-                        .unwrap();
-                    parse_inner(out, date);
+                    let mut steps = rule.into_inner(); // HEADER then GRADUATION_DATE
+                    for field in steps.next().into_iter().flat_map(Pair::into_inner) {
+                        parse_inner(out, context, field)?;
+                    }
+                    if let Some(graduation) = steps.next() {
+                        let date = AuditParser::next_inner(graduation)?; // Skip GRAD_STRING
+                        parse_inner(out, context, date)?;
+                    }
+                }
+                Rule::PREPARED => {
+                    let run = AuditParser::next_inner(rule)?; // Reach in for RUN_DATE
+                    let prepared = NaiveDateTime::parse_from_str(run.as_str(), "%D - %H:%M")
+                        .map_err(|_| AuditError::RunDate(Location::from(&run)))?;
+                    out.header.prepared = Some(prepared);
+                }
+                Rule::STUDENT
+                | Rule::NUID
+                | Rule::COLLEGE
+                | Rule::HEADER_DEGREE
+                | Rule::ADVISOR => {
+                    let field = match rule.as_rule() {
+                        Rule::STUDENT => &mut out.header.name,
+                        Rule::NUID => &mut out.header.nuid,
+                        Rule::COLLEGE => &mut out.header.college,
+                        Rule::HEADER_DEGREE => &mut out.header.degree,
+                        _ => &mut out.header.advisor,
+                    };
+                    let value = AuditParser::next_inner(rule)?; // Reach in for HEADER_VALUE
+                    let value = value.as_str().trim();
+                    *field = Some(value.to_string()).filter(|_| !value.is_empty());
                 }
                 Rule::CATALOG_PARSER => {
-                    // Reach in to parser and get CATALOG_NUM
-                    let year = rule.into_inner().next().unwrap();
-                    parse_inner(out, year);
+                    let catalog = AuditParser::next_inner(rule)?; // Move in to CATALOG_YEAR
+                    let year = AuditParser::next_inner(catalog)?; // Skip CATALOG_STRING
+                    parse_inner(out, context, year)?;
+                }
+                Rule::PROGRAMS | Rule::OPTIONS => {
+                    for child in rule.into_inner() {
+                        parse_inner(out, context, child)?;
+                    }
+                }
+                Rule::DEGREE => {
+                    // A long title wraps onto further lines
+                    let line = rule.as_str().trim();
+                    out.program = Some(match out.program.take() {
+                        Some(title) => Cow::Owned(format!("{} {}", title, line)),
+                        None => Cow::from(line),
+                    });
                 }
                 Rule::MAJOR => {
-                    // Simple rule, just convert to string.
-                    let mut majors = rule
-                        .as_str()
-                        .split('\n')
-                        .map(Cow::from)
-                        .collect::<Vec<Cow<'_, str>>>();
-                    out.majors.append(&mut majors);
+                    let name = AuditParser::next_inner(rule)?; // Reach in for PROGRAM_NAME
+                    out.majors.push(Cow::from(name.as_str().trim()));
+                }
+                Rule::MINOR => {
+                    let name = AuditParser::next_inner(rule)?; // Reach in for PROGRAM_NAME
+                    out.minors.push(Cow::from(name.as_str().trim()));
+                }
+                Rule::CONCENTRATION => {
+                    let name = AuditParser::next_inner(rule)?; // Reach in for PROGRAM_NAME
+                    out.concentrations.push(Cow::from(name.as_str().trim()));
                 }
                 Rule::DATE => {
-                    let date = NaiveDate::parse_from_str(rule.as_str(), "%D").unwrap();
+                    let date = NaiveDate::parse_from_str(rule.as_str(), "%D")
+                        .map_err(|_| AuditError::Date(Location::from(&rule)))?;
                     out.grad_date = date;
                 }
                 Rule::CATALOG_NUM => {
-                    let date = rule.as_str().parse::<isize>().unwrap();
-                    out.audit_year = date;
+                    let year: isize = AuditParser::to_num(&rule)?;
+                    out.catalog_year = year
+                        .try_into()
+                        .map_err(|_| AuditError::CatalogYear(Location::from(&rule)))?;
+                    out.audit_year = out.catalog_year.start;
                 }
                 Rule::COURSE_OPTION => {
                     // Reach into rule and recurse to NUPATH_PARSER, COURSE_LIST_PARSER, or COURSE_PARSER
-                    parse_inner(out, rule.into_inner().next().unwrap());
+                    parse_inner(out, context, AuditParser::next_inner(rule)?)?;
                 }
                 Rule::NUPATH_PARSER => {
                     // Reach in to rule and get STATUS
-                    let info = AuditParser::extract_nupath(rule);
-                    match info {
-                        (Some(Status::OK), Some(val)) => out.complete_nupaths.push(val),
-                        (Some(Status::IP), Some(val)) => out.ip_nupaths.push(val),
-                        (Some(Status::NO), Some(val)) => out.required_nupaths.push(val),
-                        _ => eprintln!("WARNING: Incorrect parsing/rule for NUPath"),
+                    let location = Location::from(&rule);
+                    let (status, id, name) = match AuditParser::extract_nupath(rule)? {
+                        (Some(status), Some(id), name) => (status, id, name),
+                        _ => return Err(AuditError::Unexpected(location)),
+                    };
+                    // The audit repeats the NUPath block, only record the first status of each
+                    if !out.nupaths.contains_key(&id) {
+                        let list = match status {
+                            Status::OK => &mut out.complete_nupaths,
+                            Status::IP => &mut out.ip_nupaths,
+                            Status::NO => &mut out.required_nupaths,
+                        };
+                        list.push(id);
+                        out.nupaths.insert(
+                            id,
+                            NUPathResult {
+                                name,
+                                status,
+                                courses: vec![],
+                            },
+                        );
+                    }
+                    context.current_nupath = Some(id);
+                }
+                Rule::NUPATH_HEADER => {
+                    // Not a requirement section, the NUPaths below are tracked on their own
+                    context.current_nupath = None;
+                }
+                Rule::SECTION_PARSER => {
+                    context.current_nupath = None;
+                    let (section, is_numbered) = AuditParser::extract_section(rule)?;
+                    match out.sections.last_mut() {
+                        Some(parent) if is_numbered => parent.sections.push(section),
+                        _ => out.sections.push(section),
+                    }
+                }
+                Rule::GROUP_PARSER => {
+                    if let Some(section) = out.current_section() {
+                        AuditParser::extract_group(section, rule)?;
+                    }
+                }
+                Rule::COUNTER_PARSER => {
+                    if let Some(section) = out.current_section() {
+                        AuditParser::extract_counter(section, rule)?;
                     }
                 }
                 Rule::COURSE_LIST_PARSER => {
-                    let mut required_courses = AuditParser::extract_course_list(rule);
+                    let mut required_courses = AuditParser::extract_course_list(rule)?;
+                    if let Some(section) = out.current_section() {
+                        let num_required = section.num_required();
+                        for requirement in &mut required_courses {
+                            requirement.num_required = num_required;
+                        }
+                        AuditParser::add_options(section, &required_courses);
+                    }
                     out.required_courses.append(&mut required_courses);
                 }
                 Rule::COURSE_PARSER => {
-                    let (course, is_in_progress) = AuditParser::extract_course(rule);
-                    if is_in_progress {
-                        out.ip_courses.push(course);
+                    let (course, is_in_progress) = AuditParser::extract_course(rule)?;
+                    match context
+                        .current_nupath
+                        .and_then(|id| out.nupaths.get_mut(&id))
+                    {
+                        Some(result) => {
+                            if !result.courses.contains(&course) {
+                                result.courses.push(course.clone());
+                            }
+                        }
+                        None => {
+                            if let Some(section) = out.current_section() {
+                                section.courses.push(course.clone());
+                            }
+                        }
+                    }
+                    let list = if is_in_progress {
+                        &mut out.ip_courses
                     } else {
-                        out.complete_courses.push(course);
+                        &mut out.complete_courses
+                    };
+                    // Listed under its NUPath and again under its requirement block
+                    if !list.iter().any(|taken| taken.is_same_attempt(&course)) {
+                        list.push(course);
+                    }
+                }
+                Rule::TRANSFER_PARSER => {
+                    let credit = AuditParser::extract_transfer(rule)?;
+                    if !out.transfer_credits.contains(&credit) {
+                        out.transfer_credits.push(credit);
                     }
                 }
                 Rule::INFO => {
-                    AuditParser::extract_info(&mut out, rule);
+                    AuditParser::extract_info(out, rule)?;
                 }
-                _ => eprintln!("SKipping"),
+                _ => return Err(AuditError::Unexpected(Location::from(&rule))),
             }
+            Ok(())
         }
-        main.into_inner().for_each(|rule| {
-            parse_inner(&mut out, rule);
-        });
+        let mut context = Context::default();
+        for rule in main.into_inner() {
+            parse_inner(&mut out, &mut context, rule)?;
+        }
+        out.defer_unfinished();
         Ok(out)
     }
 
-    fn extract_nupath(rules: Pair<'_, Rule>) -> (Option<Status>, Option<NUPath>) {
+    /// Parse a Web Audit saved as html, without stripping it to text first.
+    pub fn parse_audit_html(html: &str) -> Result<Audit<'static>, AuditError> {
+        let text = strip_html(html);
+        Self::parse_audit(&text).map(Audit::into_owned)
+    }
+
+    fn extract_nupath(
+        rules: Pair<'_, Rule>,
+    ) -> Result<(Option<Status>, Option<NUPath>, String), AuditError> {
         // NUPATH has 3 significant Rules: STATUS, NUPATH_NAME, NUPATH_ID.
-        // Right now, we only care about STATUS and NUPATH_ID.
-        // So, we iterate through all children of NUPATH, skipping the ones we don't use.
-        rules.into_inner().fold((None, None), |acc, pair| {
-            let (status, id): (Option<Status>, Option<NUPath>) = acc;
+        // So, we iterate through all children of NUPATH, collecting each.
+        rules
+            .into_inner()
+            .try_fold((None, None, String::new()), |acc, pair| {
+                let (status, id, name): (Option<Status>, Option<NUPath>, String) = acc;
+                match pair.as_rule() {
+                    Rule::STATUS => {
+                        let status_str = pair.as_str().to_string();
+                        let status = status_str
+                            .try_into()
+                            .map_err(|_| AuditError::Status(Location::from(&pair)))?;
+                        Ok((Some(status), id, name))
+                    }
+                    Rule::NUPATH_NAME => Ok((status, id, pair.as_str().trim().to_string())),
+                    Rule::NUPATH_ID => {
+                        let id_str = pair.as_str().to_string();
+                        let id = id_str
+                            .try_into()
+                            .map_err(|_| AuditError::NUPath(Location::from(&pair)))?;
+                        Ok((status, Some(id), name))
+                    }
+                    _ => Ok((status, id, name)),
+                }
+            })
+    }
+
+    /// Returns the section and bool representing whether it is a numbered sub-requirement
+    fn extract_section(rules: Pair<'_, Rule>) -> Result<(AuditSection, bool), AuditError> {
+        let mut status = Status::NO;
+        let mut name = String::new();
+        let mut is_numbered = false;
+        for pair in rules.into_inner() {
             match pair.as_rule() {
                 Rule::STATUS => {
-                    let status_str = pair.as_str().to_string();
-                    (Some(status_str.try_into().unwrap()), id)
+                    status = pair
+                        .as_str()
+                        .to_string()
+                        .try_into()
+                        .map_err(|_| AuditError::Status(Location::from(&pair)))?;
+                }
+                Rule::SECTION_NUMBER => is_numbered = true,
+                Rule::SECTION_NAME => name = pair.as_str().to_string(),
+                _ => return Err(AuditError::Unexpected(Location::from(&pair))),
+            }
+        }
+        let section = AuditSection {
+            status,
+            requirement: MajorRequirement::And(Section {
+                name,
+                ..Section::default()
+            }),
+            num_courses: None,
+            earned: None,
+            needs: None,
+            courses: vec![],
+            sections: vec![],
+        };
+        Ok((section, is_numbered))
+    }
+
+    /// Applies "Complete all/N courses/N credits of the following" to `section`
+    fn extract_group(section: &mut AuditSection, rules: Pair<'_, Rule>) -> Result<(), AuditError> {
+        let mut count = None;
+        let mut is_all = false;
+        let mut is_credits = false;
+        for pair in rules.into_inner() {
+            match pair.as_rule() {
+                Rule::ALL => is_all = true,
+                Rule::COUNT => count = Some(Self::to_num(&pair)?),
+                Rule::COURSES_UNIT => (),
+                Rule::CREDITS_UNIT => is_credits = true,
+                _ => return Err(AuditError::Unexpected(Location::from(&pair))),
+            }
+        }
+        let placeholder = MajorRequirement::And(Section::default());
+        let mut group = match std::mem::replace(&mut section.requirement, placeholder) {
+            MajorRequirement::And(group) | MajorRequirement::Or(group) => group,
+            range @ MajorRequirement::Range(_) => {
+                section.requirement = range;
+                return Ok(());
+            }
+        };
+        if is_credits {
+            group.num_credits_min = count;
+        } else {
+            group.num_courses = count;
+            section.num_courses = count;
+        }
+        section.requirement = if is_all {
+            MajorRequirement::And(group)
+        } else {
+            MajorRequirement::Or(group)
+        };
+        Ok(())
+    }
+
+    /// Records an "EARNED:" or "NEEDS:" counter on `section`
+    fn extract_counter(
+        section: &mut AuditSection,
+        rules: Pair<'_, Rule>,
+    ) -> Result<(), AuditError> {
+        let rule = Self::next_inner(rules)?; // Reach in for EARNED or NEEDS
+        let is_earned = rule.as_rule() == Rule::EARNED;
+        let mut counter = Counter::default();
+        for pair in rule.into_inner() {
+            match pair.as_rule() {
+                Rule::COUNTER_HOURS => {
+                    counter.hours = Some(Self::to_float(&Self::next_inner(pair)?)?);
+                    // Reach in for FLOAT
                 }
-                Rule::NUPATH_ID => {
-                    let id_str = pair.as_str().to_string();
-                    (status, Some(id_str.try_into().unwrap()))
+                Rule::COUNTER_COURSES => {
+                    counter.courses = Some(Self::to_num(&Self::next_inner(pair)?)?);
+                    // Reach in for NUM_COURSE
                 }
-                _ => (status, id),
+                _ => return Err(AuditError::Unexpected(Location::from(&pair))),
             }
-        })
+        }
+        if is_earned {
+            section.earned = Some(counter);
+        } else {
+            section.needs = Some(counter);
+        }
+        Ok(())
     }
 
-    /// Returns complete course and bool representing `ifInProgress`
-    fn extract_course(rules: Pair<'_, Rule>) -> (CompleteCourse, bool) {
-        let mut course = CompleteCourse::default();
-        let mut in_progress = false;
-        rules.into_inner().for_each(|pair| match pair.as_rule() {
-            Rule::YEAR => {
-                let year_str = pair.as_str();
-                let season_str: Cow<'_, str> = year_str.chars().take(2).collect();
-                let year_str: Cow<'_, str> = year_str.chars().skip(2).collect();
-                course.season = season_str.try_into().unwrap();
-                course.year = year_str.parse::<isize>().unwrap();
-                course.term_id = Self::get_termid(course.season, course.year);
+    /// Records a `Course List:` as the options of `section`
+    fn add_options(section: &mut AuditSection, list: &[Requirement]) {
+        let is_required = matches!(section.requirement, MajorRequirement::And(_));
+        let group = match &mut section.requirement {
+            MajorRequirement::And(group) | MajorRequirement::Or(group) => group,
+            MajorRequirement::Range(_) => return,
+        };
+        let credits_required = group.num_credits_min.unwrap_or(0);
+        let options = list.iter().map(|requirement| {
+            let subject = requirement.subject.clone().unwrap_or_default();
+            match requirement.class_id_2 {
+                Some(end) => courses::Requirement::CourseRange(CourseRange {
+                    credits_required,
+                    ranges: vec![SubjectRange {
+                        subject,
+                        id_range_start: requirement.class_id,
+                        id_range_end: end,
+                    }],
+                }),
+                None => courses::Requirement::RequiredCourse(Course {
+                    class_id: requirement.class_id,
+                    subject,
+                    is_required,
+                }),
             }
-            Rule::COURSE => {
-                let subject = pair.as_str().to_string();
-                course.subject = subject;
+        });
+        group.requirements.extend(options);
+    }
+
+    /// Returns complete course and bool representing `ifInProgress`, as far as the grade
+    /// column tells.
+    fn extract_course(rules: Pair<'_, Rule>) -> Result<(CompleteCourse, bool), AuditError> {
+        let location = Location::from(&rules);
+        let mut pairs = rules.into_inner();
+        // Every course starts with its YEAR and COURSE
+        let (term, code) = match (pairs.next(), pairs.next()) {
+            (Some(term), Some(code)) => (term, code),
+            _ => return Err(AuditError::Unexpected(location)),
+        };
+        let (subject, class_id) = Self::extract_code(code)?;
+        let mut course = CompleteCourse::new(subject, class_id, Self::extract_term(&term)?);
+        let mut in_progress = false;
+        for pair in pairs {
+            Self::extract_course_field(&mut course, &mut in_progress, pair)?;
+        }
+        Ok((course, in_progress))
+    }
+
+    /// Term of a `YEAR`, i.e. FL20
+    fn extract_term(pair: &Pair<'_, Rule>) -> Result<TermId, AuditError> {
+        let (season, year) = pair.as_str().split_at(2);
+        let season: Season = Cow::from(season)
+            .try_into()
+            .map_err(|_| AuditError::Season(Location::from(pair)))?;
+        let year = year
+            .parse::<isize>()
+            .map_err(|_| AuditError::Number(Location::from(pair)))?;
+        Ok(TermId::from_audit(season, year))
+    }
+
+    /// Subject and number of a `COURSE`, i.e. ("CS", 2500)
+    fn extract_code(pair: Pair<'_, Rule>) -> Result<(String, isize), AuditError> {
+        let mut subject = String::new();
+        let mut class_id = 0;
+        for pair in pair.into_inner() {
+            match pair.as_rule() {
+                Rule::ID => subject = pair.as_str().trim().to_string(),
+                Rule::COURSE_NUMBER => class_id = Self::to_num(&pair)?,
+                _ => return Err(AuditError::Unexpected(Location::from(&pair))),
             }
+        }
+        Ok((subject, class_id))
+    }
+
+    fn extract_grade(pair: &Pair<'_, Rule>) -> Result<Grade, AuditError> {
+        pair.as_str()
+            .to_string()
+            .try_into()
+            .map_err(|_| AuditError::Grade(Location::from(pair)))
+    }
+
+    /// Fill in the part of `course` a `COURSE_PARSER` child after the COURSE holds.
+    fn extract_course_field(
+        course: &mut CompleteCourse,
+        in_progress: &mut bool,
+        pair: Pair<'_, Rule>,
+    ) -> Result<(), AuditError> {
+        match pair.as_rule() {
             Rule::CREDITS => {
-                let credits = pair.as_str();
-                course.credit_hours = credits.parse::<f32>().unwrap();
+                course.credit_hours = Self::to_float(&pair)?;
             }
             Rule::COURSE_NAME => {
                 let name = pair.as_str().to_string();
                 course.name = name;
             }
-            Rule::MAYBE_IP => {
-                let as_str = pair.as_str().to_string();
-                if as_str.contains("IP") {
-                    in_progress = false;
+            Rule::GRADE => {
+                let grade = Self::extract_grade(&pair)?;
+                // A placeholder means no grade has been posted yet
+                *in_progress = grade.is_in_progress();
+                course.withdrawn = grade.is_withdrawal();
+                course.grade = Some(grade);
+            }
+            Rule::MARKER => match pair.as_str() {
+                "(HON)" => course.hon = true,
+                ">R" => course.repeated = true,
+                ">X" | ">D" => course.excluded = true,
+                _ => (),
+            },
+            _ => return Err(AuditError::Unexpected(Location::from(&pair))),
+        }
+        Ok(())
+    }
+
+    fn extract_transfer(rules: Pair<'_, Rule>) -> Result<TransferCredit, AuditError> {
+        let mut credit = TransferCredit {
+            source: CreditSource::Transfer,
+            institution: String::new(),
+            subject: String::new(),
+            class_id: 0,
+            name: String::new(),
+            credit_hours: 0_f32,
+            grade: None,
+            term_id: None,
+        };
+        for pair in rules.into_inner() {
+            match pair.as_rule() {
+                Rule::CREDIT_SOURCE => {
+                    credit.source = pair
+                        .as_str()
+                        .to_string()
+                        .try_into()
+                        .map_err(|_| AuditError::Unexpected(Location::from(&pair)))?;
                 }
-                if as_str.contains("(HON)") {
-                    course.hon = true;
+                Rule::INSTITUTION => credit.institution = pair.as_str().trim().to_string(),
+                Rule::YEAR => credit.term_id = Some(Self::extract_term(&pair)?),
+                Rule::COURSE => {
+                    let (subject, class_id) = Self::extract_code(pair)?;
+                    credit.subject = subject;
+                    credit.class_id = class_id;
                 }
+                Rule::CREDITS => credit.credit_hours = Self::to_float(&pair)?,
+                Rule::COURSE_NAME => credit.name = pair.as_str().to_string(),
+                Rule::GRADE => credit.grade = Some(Self::extract_grade(&pair)?),
+                Rule::MARKER => (),
+                _ => return Err(AuditError::Unexpected(Location::from(&pair))),
             }
-            _ => unreachable!(),
-        });
-        (course, in_progress)
+        }
+        Ok(credit)
     }
 
-    fn extract_course_list(rules: Pair<'_, Rule>) -> Vec<Requirement> {
+    fn extract_course_list(rules: Pair<'_, Rule>) -> Result<Vec<Requirement>, AuditError> {
         let mut requirements = Vec::new();
         let mut prev_id: bool = false;
         let mut last_subject = None;
 
-        rules.into_inner().for_each(|pair| match pair.as_rule() {
-            Rule::COURSE => {
-                let mut requirement = Requirement::default();
-                pair.into_inner().for_each(|pair| match pair.as_rule() {
-                    Rule::COURSE_NUMBER if !prev_id => {
-                        requirement.class_id = AuditParser::to_num(pair.as_str()).unwrap();
-                    }
-                    Rule::COURSE_NUMBER if prev_id => {
-                        requirement = requirements.pop().unwrap();
-                        requirement.class_id_2 = Some(AuditParser::to_num(pair.as_str()).unwrap());
+        for pair in rules.into_inner() {
+            match pair.as_rule() {
+                Rule::COURSE => {
+                    let mut requirement = Requirement::default();
+                    for pair in pair.into_inner() {
+                        match pair.as_rule() {
+                            Rule::COURSE_NUMBER if !prev_id => {
+                                requirement.class_id = AuditParser::to_num(&pair)?;
+                            }
+                            Rule::COURSE_NUMBER if prev_id => {
+                                requirement = requirements
+                                    .pop()
+                                    .ok_or_else(|| AuditError::Unexpected(Location::from(&pair)))?;
+                                requirement.class_id_2 = Some(AuditParser::to_num(&pair)?);
+                            }
+                            Rule::ID => {
+                                requirement.subject = Some(pair.as_str().trim().to_string());
+                                last_subject = requirement.subject.clone();
+                            }
+                            _ => return Err(AuditError::Unexpected(Location::from(&pair))),
+                        }
                     }
-                    Rule::ID => {
-                        requirement.subject = Some(pair.as_str().trim().to_string());
-                        last_subject = requirement.subject.clone();
+                    if requirement.subject.is_none() {
+                        requirement.subject = last_subject.clone();
                     }
-                    _ => unreachable!(),
-                });
-                if requirement.subject.is_none() {
-                    requirement.subject = last_subject.clone();
+                    requirements.push(requirement);
+                    prev_id = false;
                 }
-                requirements.push(requirement);
-                prev_id = false;
-            }
-            Rule::TO => {
-                prev_id = true;
+                Rule::TO => {
+                    prev_id = true;
+                }
+                _ => return Err(AuditError::Unexpected(Location::from(&pair))),
             }
-            _ => unreachable!(),
-        });
+        }
 
-        requirements
+        Ok(requirements)
     }
 
-    fn extract_info(audit: &mut AuditToJson<'_>, rule: Pair<'_, Rule>) {
-        rule.into_inner().for_each(|pair| {
+    fn extract_info(audit: &mut Audit<'_>, rule: Pair<'_, Rule>) -> Result<(), AuditError> {
+        for pair in rule.into_inner() {
             match pair.as_rule() {
                 Rule::EARNED_HOURS => {
-                    audit.earned_hours = pair
-                        .into_inner()
-                        .next()
-                        .unwrap() // Reach in for FLOAT
-                        .as_str()
-                        .parse::<f32>()
-                        .unwrap();
+                    audit.earned_hours = Self::to_float(&Self::next_inner(pair)?)?;
+                    // Reach in for FLOAT
                 }
                 Rule::COURSES_TAKEN => {
-                    audit.courses_taken = pair
-                        .into_inner()
-                        .next()
-                        .unwrap() // Reach in for FLOAT
-                        .as_str()
-                        .parse::<isize>()
-                        .unwrap();
+                    audit.courses_taken = Self::to_num(&Self::next_inner(pair)?)?;
+                    // Reach in for NUM_COURSE
                 }
                 Rule::ATTEMPTED_HOURS => {
-                    audit.attempted_hours = pair
-                        .into_inner()
-                        .next()
-                        .unwrap() // Reach in for FLOAT
-                        .as_str()
-                        .parse::<f32>()
-                        .unwrap();
+                    audit.attempted_hours = Self::to_float(&Self::next_inner(pair)?)?;
+                    // Reach in for FLOAT
                 }
                 Rule::POINTS => {
-                    audit.points = pair
-                        .into_inner()
-                        .next()
-                        .unwrap() // Reach in for FLOAT
-                        .as_str()
-                        .parse::<f32>()
-                        .unwrap();
+                    audit.points = Self::to_float(&Self::next_inner(pair)?)?; // Reach in for FLOAT
                 }
                 Rule::GPA => {
-                    audit.gpa = pair
-                        .into_inner()
-                        .next()
-                        .unwrap() // Reach in for FLOAT
-                        .as_str()
-                        .parse::<f32>()
-                        .unwrap();
+                    audit.gpa = Self::to_float(&Self::next_inner(pair)?)?; // Reach in for FLOAT
                 }
-                _ => unreachable!(),
+                _ => return Err(AuditError::Unexpected(Location::from(&pair))),
             }
-        });
+        }
+        Ok(())
+    }
+
+    /// First child of `pair`, which the grammar guarantees exists.
+    fn next_inner(pair: Pair<'_, Rule>) -> Result<Pair<'_, Rule>, AuditError> {
+        let location = Location::from(&pair);
+        pair.into_inner()
+            .next()
+            .ok_or(AuditError::Unexpected(location))
+    }
+
+    /// First pair of a successful parse, which is always the requested rule.
+    fn first_inner(
+        mut pairs: pest::iterators::Pairs<'_, Rule>,
+    ) -> Result<Pair<'_, Rule>, AuditError> {
+        pairs.next().ok_or_else(|| {
+            AuditError::Unexpected(Location {
+                text: String::new(),
+                line: 1,
+                column: 1,
+            })
+        })
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn to_num(pair: &Pair<'_, Rule>) -> Result<isize, AuditError> {
+        pair.as_str()
+            .trim()
+            .parse::<isize>()
+            .map_err(|_| AuditError::Number(Location::from(pair)))
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn to_float(pair: &Pair<'_, Rule>) -> Result<f32, AuditError> {
+        pair.as_str()
+            .trim()
+            .parse::<f32>()
+            .map_err(|_| AuditError::Number(Location::from(pair)))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::constants::{
+        majors::Concentration,
+        schedule::{AllScheduleCourse, ScheduleCourse},
+        tracking::CourseTakenTracker,
+        AllCourses,
+    };
+    use std::fs;
+
+    /// The synthetic Web Audit the parser tests share.
+    pub(crate) const FIXTURE: &str = include_str!("../tests/fixtures/WebAudit.txt");
+
+    /// [`FIXTURE`](FIXTURE), parsed.
+    pub(crate) fn fixture() -> Audit<'static> {
+        AuditParser::parse_audit(FIXTURE).unwrap()
+    }
+
+    /// Parse `body` below a graduation date, catalog year and major.
+    fn parse(body: &str) -> Audit<'static> {
+        let audit = format!(
+            "GRADUATION DATE: 05/20/21 CATALOG YEAR: 202021\nCS - Major\n{}",
+            body
+        );
+        AuditParser::parse_audit(&audit).unwrap().into_owned()
+    }
+
+    #[test]
+    fn splits_course_codes() {
+        let audit = serde_json::to_value(fixture()).unwrap();
+        let courses = audit["complete_courses"].as_array().unwrap();
+        let has = |subject: &str, class_id: i64| {
+            courses
+                .iter()
+                .any(|c| c["subject"] == subject && c["class_id"] == class_id)
+        };
+        assert!(has("MATH", 1365));
+        assert!(has("CS", 2500));
+        assert!(courses.iter().all(|c| c["class_id"] != 0));
+    }
+
+    #[test]
+    fn separates_in_progress_courses() {
+        let audit = serde_json::to_value(fixture()).unwrap();
+        let codes = |key: &str| {
+            audit[key]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|c| format!("{}{}", c["subject"].as_str().unwrap(), c["class_id"]))
+                .collect::<Vec<_>>()
+        };
+        let in_progress = codes("ip_courses");
+        let complete = codes("complete_courses");
+        for course in &["ENGW3302", "ARTG4700", "ARTG3700", "CS4530", "THTR1170"] {
+            assert!(
+                in_progress.iter().any(|c| c == course),
+                "{} not in progress",
+                course
+            );
+            assert!(
+                !complete.iter().any(|c| c == course),
+                "{} marked complete",
+                course
+            );
+        }
+        assert!(complete.iter().any(|c| c == "CS2500"));
+    }
+
+    #[test]
+    fn future_terms_are_in_progress() {
+        let courses = "\x20FL20 CS  2500  4.00       Fundamentals of Computer Sci 1\n\
+                       \x20FL20 CS  1800  4.00 A     Discrete Structures\n\
+                       \x20SP21 CS  2510  4.00       Fundamentals of Computer Sci 2\n\
+                       \x20SP20 CS  1200  1.00       First Year Seminar\n";
+        let audit = format!(
+            " PREPARED: 10/15/20 - 14:32\n\
+             \x20GRADUATION DATE: 05/20/21 CATALOG YEAR: 202021\nCS - Major\n{}",
+            courses
+        );
+        let audit = AuditParser::parse_audit(&audit).unwrap();
+        let codes = |courses: &[CompleteCourse]| -> Vec<isize> {
+            courses.iter().map(|course| course.class_id).collect()
+        };
+        assert_eq!(codes(audit.ip_courses()), vec![2500, 2510]);
+        assert_eq!(codes(audit.complete_courses()), vec![1800, 1200]);
+
+        // Without a run date the latest term with a course in progress is the current one
+        let audit = parse(&format!(
+            "{}\x20FL20 CS  3500  4.00 IP    Object-Oriented Design\n",
+            courses
+        ));
+        assert_eq!(codes(audit.ip_courses()), vec![3500, 2500, 2510]);
+        assert_eq!(codes(audit.complete_courses()), vec![1800, 1200]);
+    }
+
+    #[test]
+    fn keeps_full_course_titles() {
+        let audit = parse(
+            "\x20SP16 ARTF2220  3.00 T     Introduction to Digital Art:  Foundations\n\
+             \x20FL17 CS  1800  4.00 A-    Discrete Structures (HON)\n\
+             \x20FL17 ENGL1111  4.00       A Study  of Writing  \n\
+             \x20FL20 CS  4100  4.00       AI  Foundations\n",
+        );
+
+        let courses = audit.complete_courses();
+        assert_eq!(courses[0].name, "Introduction to Digital Art:  Foundations");
+        assert_eq!(courses[1].name, "Discrete Structures");
+        assert!(courses[1].hon);
+        assert_eq!(courses[2].name, "A Study  of Writing");
+        // Only the column after the credits holds a grade, not the title
+        assert_eq!(courses[3].name, "AI  Foundations");
+        assert_eq!(courses[3].grade, None);
+    }
+
+    #[test]
+    fn captures_grades() {
+        let audit = parse(
+            "\x20FL17 CS  2500  4.00 A     Fundamentals of Computer Sci 1\n\
+             \x20FL17 CS  1800  4.00 B+    Discrete Structures\n\
+             \x20FL17 CS  1802  1.00 W     Seminar for CS 1800\n\
+             \x20SP16 MATH1342  3.00 T     Calculus II\n",
+        );
+        let gpa = audit.gpa_by_term();
+        let term = |label: &str| label.parse::<TermId>().unwrap();
+        assert!((gpa[&term("Fall 2017")] - (4.0 * 4.0 + 3.333 * 4.0) / 8.0).abs() < 1e-4);
+        assert!(!gpa.contains_key(&term("Spring 2016")));
+
+        let grades = audit
+            .complete_courses()
+            .iter()
+            .map(|c| c.grade.unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(grades, vec!["A", "B+", "W", "T"]);
+
+        let unknown = "GRADUATION DATE: 05/20/21 CATALOG YEAR: 202021\nCS - Major\n\
+                       \x20FL17 CS  2500  4.00 Q     Fundamentals of Computer Sci 1\n";
+        match AuditParser::parse_audit(unknown) {
+            Err(AuditError::Grade(loc)) => assert_eq!(loc.text, "Q"),
+            other => panic!("expected a grade error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn lists_each_attempt_once() {
+        let audit = parse(
+            "\n OK   Formal/Quantitative Reasoning (FQ)\n\
+             \x20        FL17 CS  2500  4.00 A     Fundamentals of Computer Sci 1\n\
+             \n OK   Computer Science Fundamental Courses\n\
+             \x20        FL17 CS  2500  4.00 A     Fundamentals of Computer Sci 1\n\
+             \x20        FL17 CS  1800  4.00 C     Discrete Structures\n",
+        );
+        assert_eq!(audit.complete_courses().len(), 2);
+        assert_eq!(audit.sections()[0].courses.len(), 2);
+        assert_eq!(audit.nupaths()[&NUPath::FQ].courses.len(), 1);
+    }
+
+    #[test]
+    fn averages_each_attempt_once() {
+        let audit = parse(
+            "\n OK   Formal/Quantitative Reasoning (FQ)\n\
+             \x20        FL17 CS  2500  4.00 A     Fundamentals of Computer Sci 1\n\
+             \n OK   Computer Science Fundamental Courses\n\
+             \x20        FL17 CS  2500  4.00 A     Fundamentals of Computer Sci 1\n\
+             \x20        FL17 CS  1800  4.00 C     Discrete Structures\n",
+        );
+        assert_eq!(audit.gpa_by_term()[&"Fall 2017".parse().unwrap()], 3.0);
+    }
+
+    #[test]
+    fn flags_repeated_and_excluded_courses() {
+        let audit = parse(
+            "\x20FL17 CS  2500  4.00 F     Fundamentals of Computer Sci 1 >R\n\
+             \x20SP18 CS  2500  4.00 B     Fundamentals of Computer Sci 1\n\
+             \x20SP18 CS  1802  1.00 W     Seminar for CS 1800\n\
+             \x20FL17 MATH1341  4.00 A     Calculus 1 >X\n",
+        );
+        let flags: Vec<_> = audit
+            .complete_courses()
+            .iter()
+            .map(|c| (c.name.as_str(), c.repeated, c.withdrawn, c.excluded))
+            .collect();
+        assert_eq!(
+            flags,
+            vec![
+                ("Fundamentals of Computer Sci 1", true, false, false),
+                ("Fundamentals of Computer Sci 1", false, false, false),
+                ("Seminar for CS 1800", false, true, false),
+                ("Calculus 1", false, false, true),
+            ]
+        );
+
+        let gpa = audit.gpa_by_term();
+        assert_eq!(gpa.len(), 1);
+        assert_eq!(gpa[&"Spring 2018".parse().unwrap()], 3.0);
+    }
+
+    #[test]
+    fn splits_majors_and_minors() {
+        let audit = "GRADUATION DATE: 05/20/21 CATALOG YEAR: 202021\n\
+                     BS Computer Science and Design\n\
+                     Computer Science - Major\n\
+                     Interaction Design - Major\n\
+                     Mathematics - Minor\n\
+                     Music - Minor\n\n\
+                     \x20At least 134 semester hours are required for this degree.\n";
+
+        let audit = AuditParser::parse_audit(audit).unwrap();
+        assert_eq!(audit.program(), Some("BS Computer Science and Design"));
+        assert_eq!(audit.majors(), &["Computer Science", "Interaction Design"]);
+        assert_eq!(audit.minors(), &["Mathematics", "Music"]);
+    }
+
+    #[test]
+    fn parses_undeclared_audit() {
+        let audit = "GRADUATION DATE: 05/20/21 CATALOG YEAR: 202021\n\
+                     BS Undeclared\n\n\
+                     \x20NO   NUpath Requirements\n\
+                     \x20OK   Natural/Designed World (ND)\n\
+                     \x20        FL15 CHEM1211  3.00 T     General Chemistry I\n";
+
+        let audit = AuditParser::parse_audit(audit).unwrap();
+        assert_eq!(audit.program(), Some("BS Undeclared"));
+        assert!(audit.majors().is_empty());
+        assert_eq!(audit.complete_nupaths(), &[NUPath::ND]);
+        assert_eq!(audit.complete_courses().len(), 1);
+    }
+
+    #[test]
+    fn parses_concentrations() {
+        let audit = parse(
+            "Software - Concentration\n\n\
+             \x20IP   Software Concentration\n\
+             \x20     Complete 2 courses from the following\n\
+             \x20        FL20 CS  4530  4.00 A     Fundamentals of Software Eng\n\
+             \x20     Course List: CS  4500 4530 4550\n\
+             \n NO   Software Development Electives\n\
+             \x20     Complete 1 course from the following\n\
+             \x20     Course List: CS  4410 4520\n",
+        );
+        assert_eq!(audit.concentrations(), &["Software"]);
+
+        let requirements = audit.concentration_requirements();
+        let names: Vec<_> = requirements.iter().map(|r| r.name()).collect();
+        assert_eq!(names, vec!["Software Concentration"]);
+        let concentration = Concentration {
+            min_options: 1,
+            max_options: 1,
+            requirements_group_map: requirements.into_iter().cloned().collect(),
+        };
+
+        let major = Major {
+            name: "Computer Science, BSCS".into(),
+            requirement_groups: vec![],
+            requirement_group_map: Default::default(),
+            year_version: 2020,
+            is_language_required: false,
+            total_credits_required: 134,
+            nu_paths: vec![],
+            concentrations: Some(concentration),
+        };
+        let evaluation = audit.evaluate(&major);
+        assert_eq!(evaluation.groups.len(), 1);
+        assert_eq!(evaluation.groups[0].name, "Software Concentration");
+        // One of the two courses the block asks for
+        assert_eq!(evaluation.groups[0].progress, evaluation::Progress::Partial);
+        let mut completed = audit.complete_courses().to_vec();
+        completed.push(CompleteCourse::new(
+            "CS".into(),
+            4500,
+            "Spring 2021".parse().unwrap(),
+        ));
+        let evaluation =
+            evaluation::evaluate_with_concentrations(&major, &["Software"], &completed, &[]);
+        assert_eq!(
+            evaluation.groups[0].progress,
+            evaluation::Progress::Satisfied
+        );
+
+        let undeclared = evaluation::evaluate(&major, audit.complete_courses(), &[]);
+        assert_eq!(undeclared.groups[0].name, "Concentration");
+        assert_eq!(
+            undeclared.groups[0].progress,
+            evaluation::Progress::Unsatisfied
+        );
+    }
+
+    #[test]
+    fn builds_requirement_tree() {
+        let audit = fixture();
+        let names: Vec<_> = audit
+            .sections()
+            .iter()
+            .map(|section| section.requirement.name())
+            .collect();
+        assert!(!names.contains(&"NUpath Requirements"));
+        let section = |name: &str| {
+            let section = audit
+                .sections()
+                .iter()
+                .find(|section| section.requirement.name() == name)
+                .unwrap_or_else(|| panic!("no section {}", name));
+            serde_json::to_value(section).unwrap()
+        };
+
+        let required = section("Computer Science Required Courses");
+        assert_eq!(required["status"], "IP");
+        let core = &required["sections"][2];
+        assert_eq!(core["requirement"]["And"]["name"], "Computer Science Core");
+        assert_eq!(core["courses"].as_array().unwrap().len(), 3);
+
+        let psychology = section("Psychology Requirement");
+        assert_eq!(psychology["num_courses"], 1);
+        assert_eq!(
+            psychology["requirement"]["Or"]["requirements"][0]["RequiredCourse"]["class_id"],
+            1101
+        );
+        let electives = &section("Computer Science Electives")["requirement"]["Or"];
+        assert_eq!(electives["num_credits_min"], 8);
+        assert_eq!(
+            electives["requirements"][1]["CourseRange"]["ranges"][0]["id_range_end"],
+            4999
+        );
+    }
+
+    #[test]
+    fn reads_sections_from_line_starts() {
+        let audit = parse(
+            "\n IP   Security Electives\n\
+             \x20        FL20 CY  4740 12.00 IP    Network Security\n\
+             \x20     See your advisor NO   comment here\n",
+        );
+        let names: Vec<_> = audit
+            .sections()
+            .iter()
+            .map(|section| section.requirement.name())
+            .collect();
+        assert_eq!(names, vec!["Security Electives"]);
+        assert_eq!(audit.sections()[0].courses.len(), 1);
+        assert_eq!(audit.ip_courses()[0].credit_hours, 12.0);
+    }
+
+    #[test]
+    fn parses_requirement_counters() {
+        let audit = parse(
+            "\n IP   Security Electives\n\
+             \x20     EARNED:  4.00 HOURS   1 COURSE\n\
+             \x20      NEEDS:  8.00 HOURS   2 COURSES\n\
+             \x20        FL20 CY  4740  4.00 IP    Network Security\n\
+             \x20     SELECT FROM: CY  4740 4770 4760 4930\n\
+             \n NO   Psychology Requirement\n\
+             \x20     Complete 1 course from the following\n\
+             \x20      NEEDS:  4.00 HOURS   1 COURSE\n\
+             \x20     Course List: PSYC 1101 3452\n\
+             \n NO   Art + Design Foundations\n\
+             \x20     Complete all of the following\n\
+             \x20     Course List: ARTF 1123 2224\n",
+        );
+        let security = &audit.sections()[0];
+        assert_eq!(security.earned.unwrap().hours, Some(4.0));
+        assert_eq!(security.needs.unwrap().courses, Some(2));
+
+        let num_required: Vec<_> = audit
+            .required_courses()
+            .iter()
+            .map(|requirement| requirement.num_required)
+            .collect();
+        assert_eq!(
+            num_required,
+            vec![
+                Some(3),
+                Some(3),
+                Some(3),
+                Some(3),
+                Some(1),
+                Some(1),
+                None,
+                None
+            ]
+        );
     }
 
-    fn get_termid(season: Season, year: isize) -> isize {
-        let mut term_id = String::from("20");
-        term_id.push_str(year.to_string().as_str());
-        use Season::*;
-        match season {
-            FL => {
-                term_id = String::from("20");
-                term_id.push_str((year + 1).to_string().as_str());
-                term_id.push_str("10");
+    #[test]
+    fn deduplicates_nupaths() {
+        let audit = serde_json::to_value(fixture()).unwrap();
+        assert_eq!(audit["complete_nupaths"].as_array().unwrap().len(), 10);
+        assert_eq!(audit["ip_nupaths"], serde_json::json!(["WI", "WD"]));
+        assert_eq!(audit["required_nupaths"], serde_json::json!(["CE"]));
+
+        let writing = &audit["nupaths"]["WD"];
+        assert_eq!(writing["name"], "Advanced Writing in the Disciplines");
+        assert_eq!(writing["status"], "IP");
+        assert_eq!(writing["courses"].as_array().unwrap().len(), 1);
+        assert_eq!(writing["courses"][0]["class_id"], 3302);
+        assert_eq!(audit["nupaths"]["CE"]["courses"], serde_json::json!([]));
+
+        // A repeat that disagrees keeps the first status, in the map and the lists
+        let audit = parse(
+            "\n OK   Natural/Designed World (ND)\n\
+             \x20        FL15 CHEM1211  3.00 T     General Chemistry I\n\
+             \n IP   Natural/Designed World (ND)\n",
+        );
+        assert_eq!(audit.complete_nupaths(), &[NUPath::ND]);
+        assert!(audit.ip_nupaths().is_empty());
+        assert_eq!(audit.nupaths()[&NUPath::ND].status, Status::OK);
+    }
+
+    #[test]
+    fn reports_bad_graduation_date() {
+        let audit = "NAME\n GRADUATION DATE: 13/45/21\n CATALOG YEAR: 202021\nCS - Major";
+
+        match AuditParser::parse_audit(audit) {
+            Err(AuditError::Date(loc)) => {
+                assert_eq!(loc.text, "13/45/21");
+                assert_eq!((loc.line, loc.column), (2, 19));
             }
-            SP => term_id.push_str("30"),
-            S1 => term_id.push_str("40"),
-            S2 => term_id.push_str("60"),
-            SM => term_id.push_str("50"),
+            other => panic!("expected a date error, got {:?}", other),
         }
-        Self::to_num(term_id.as_str()).unwrap()
     }
 
-    #[allow(clippy::wrong_self_convention)]
-    fn to_num(input: &str) -> Result<isize, std::num::ParseIntError> {
-        isize::from_str_radix(input, 10)
+    #[test]
+    fn decodes_catalog_year() {
+        let audit = "GRADUATION DATE: 05/20/21 CATALOG YEAR: 201920\nCS - Major\n";
+        let audit = AuditParser::parse_audit(audit).unwrap();
+        assert_eq!(audit.audit_year(), 2019);
+        assert_eq!(audit.catalog_year().to_string(), "2019-2020");
+
+        let audit = serde_json::to_value(audit).unwrap();
+        assert_eq!(
+            audit["catalog_year"],
+            serde_json::json!({"start": 2019, "end": 2020})
+        );
+
+        let audit = "GRADUATION DATE: 05/20/21 CATALOG YEAR: 201921\nCS - Major\n";
+        match AuditParser::parse_audit(audit) {
+            Err(AuditError::CatalogYear(loc)) => assert_eq!(loc.text, "201921"),
+            other => panic!("expected a catalog year error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_header() {
+        let audit = " PREPARED: 10/15/20 - 14:32\n\
+                     \x20STUDENT: Husky, Paws\n\
+                     \x20NUID: 001234567\n\
+                     \x20DEGREE: Bachelor of Science\n\
+                     \x20ADVISOR: Smith, Jane\n\n\
+                     \x20GRADUATION DATE: 05/20/21\n CATALOG YEAR: 202021\nCS - Major\n";
+        let audit = AuditParser::parse_audit(audit).unwrap();
+
+        let header = audit.header();
+        assert_eq!(header.nuid.as_deref(), Some("001234567"));
+        assert_eq!(header.college, None);
+        assert_eq!(header.degree.as_deref(), Some("Bachelor of Science"));
+        assert_eq!(header.advisor.as_deref(), Some("Smith, Jane"));
+        assert_eq!(
+            header.prepared,
+            NaiveDate::from_ymd_opt(2020, 10, 15).and_then(|date| date.and_hms_opt(14, 32, 0))
+        );
+
+        let user = UserData::from(&audit);
+        assert_eq!(user.full_name.as_deref(), Some("Paws Husky"));
+        assert_eq!(user.graduation_year, Some(2021));
+        assert!(user.plan.is_some());
+
+        assert_eq!(parse("").header(), &Default::default());
+
+        let audit = " PREPARED: 10/45/20 - 14:32\n GRADUATION DATE: 05/20/21\n\
+                     \x20CATALOG YEAR: 202021\nCS - Major\n";
+        match AuditParser::parse_audit(audit) {
+            Err(AuditError::RunDate(loc)) => assert_eq!(loc.text, "10/45/20 - 14:32"),
+            other => panic!("expected a run date error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_transfer_credit() {
+        let audit = parse(
+            "\n OK   Transfer and Test Credit\n\
+             \x20        AP   Statistics                  MATH2280  4.00 T     Statistics and Software\n\
+             \x20        IB   Psychology HL               PSYC1101  4.00 T     Foundations of Psychology\n\
+             \x20        TR   Boston University     SP16  ENGW1111  4.00 T     First-Year Writing\n",
+        );
+
+        let credits = audit.transfer_credits();
+        let sources: Vec<_> = credits.iter().map(|credit| credit.source).collect();
+        assert_eq!(
+            sources,
+            vec![
+                CreditSource::AdvancedPlacement,
+                CreditSource::InternationalBaccalaureate,
+                CreditSource::Transfer
+            ]
+        );
+        assert_eq!(credits[2].institution, "Boston University");
+        assert_eq!(
+            (credits[2].subject.as_str(), credits[2].class_id),
+            ("ENGW", 1111)
+        );
+        assert_eq!(credits[2].term_id.map(isize::from), Some(201630));
+        assert_eq!(credits[0].term_id, None);
+        let entry = "Summer 2 2020".parse().unwrap();
+        assert_eq!(credits[0].to_course(entry).term_id, entry);
+        assert_eq!(
+            credits[2].to_course(entry).term_id.to_string(),
+            "Spring 2016"
+        );
+        assert_eq!(audit.transfer_hours(), 12.0);
+        // Not mistaken for courses taken at Northeastern
+        assert!(audit.complete_courses().is_empty());
+    }
+
+    #[test]
+    fn parses_html_audit() {
+        let html = fs::read_to_string("tests/fixtures/WebAudit.html").expect("cannot read file");
+
+        let audit = serde_json::to_value(AuditParser::parse_audit_html(&html).unwrap()).unwrap();
+        assert_eq!(audit["program"], "BS Computer Science and Design");
+        assert_eq!(audit["majors"], serde_json::json!(["Computer Science"]));
+        assert_eq!(audit["grad_date"], "2021-05-20");
+        assert_eq!(audit["complete_nupaths"], serde_json::json!(["ND"]));
+        assert_eq!(audit["required_courses"].as_array().unwrap().len(), 5);
+        assert_eq!(audit["gpa"], 3.301_f32 as f64);
+    }
+
+    #[test]
+    fn converts_to_graduatenu() {
+        let audit = fixture();
+
+        let rep = serde_json::to_value(InitialScheduleRep::from(&audit)).unwrap();
+        assert_eq!(
+            rep["data"]["majors"],
+            serde_json::json!(["Computer Science"])
+        );
+        assert_eq!(rep["data"]["minors"], serde_json::json!(["Mathematics"]));
+        assert_eq!(rep["data"]["gradDate"], "2021-05-20");
+        assert_eq!(
+            rep["inprogress"]["nupaths"],
+            serde_json::json!(["WI", "WD"])
+        );
+        assert_eq!(rep["requirements"]["nupaths"], serde_json::json!(["CE"]));
+
+        let course = &rep["completed"]["courses"][0];
+        assert_eq!(course["classId"], 1211);
+        assert_eq!(course["creditHours"], 3.0);
+        assert_eq!(course["termId"], 201610);
+        assert!(course.get("grade").is_none());
+        let requirement = &rep["requirements"]["courses"][0];
+        assert!(requirement["classId"].is_number());
+        assert!(requirement.get("class_id").is_none());
+    }
+
+    #[test]
+    fn builds_schedule() {
+        let audit = fixture();
+
+        let schedule = Schedule::from(&audit);
+        assert_eq!(schedule.years, (2016..=2021).collect::<Vec<_>>());
+        let status =
+            |year: isize, term: fn(&_) -> &ScheduleTerm| match term(&schedule.year_map[&year]) {
+                ScheduleTerm::Term(term) => (
+                    isize::from(term.term_id),
+                    term.status.to_string(),
+                    term.classes.len(),
+                ),
+                ScheduleTerm::Dnd(_) => panic!("expected a term"),
+            };
+        assert_eq!(status(2016, |y| &y.fall).0, 201610);
+        assert_eq!(
+            status(2016, |y| &y.summer1),
+            (201640, schedule::Status::INACTIVE.to_string(), 0)
+        );
+        assert_eq!(
+            status(2019, |y| &y.spring),
+            (201930, schedule::Status::COOP.to_string(), 0)
+        );
+        assert_eq!(
+            status(2021, |y| &y.fall),
+            (202110, schedule::Status::CLASSES.to_string(), 5)
+        );
+    }
+
+    #[test]
+    fn tracks_taken_courses() {
+        let audit = fixture();
+
+        let mut tracker = CourseTracker::from(&audit);
+        assert!(tracker.contains("CHEM 1211"));
+        let term_ids = |tracker: &CourseTracker, course: &str| -> Vec<String> {
+            let term_ids = tracker.get_term_ids(course.into());
+            term_ids.iter().map(ToString::to_string).collect()
+        };
+        assert_eq!(
+            term_ids(&tracker, "COOP 3945"),
+            vec![
+                "Spring 2019",
+                "Summer 1 2019",
+                "Spring 2020",
+                "Summer 1 2020"
+            ]
+        );
+        assert!(tracker.repeated().any(|course| course == "COOP 3945"));
+
+        let planned = ScheduleCourse {
+            name: "Web Development".into(),
+            class_id: "4550".into(),
+            subject: "CS".into(),
+            pre_reqs: None,
+            co_reqs: None,
+            num_credits_min: 4,
+            num_credits_max: 4,
+        };
+        tracker.add_courses(
+            vec![
+                AllCourses::Model(AllScheduleCourse::Course(planned)),
+                AllCourses::Model(AllScheduleCourse::Dnd("dnd".into())),
+            ],
+            "Spring 2021".parse().unwrap(),
+        );
+        assert_eq!(term_ids(&tracker, "CS 4550"), vec!["Spring 2021"]);
+        assert!(tracker.get_term_ids("CS 9999".into()).is_empty());
     }
 }
@@ -0,0 +1,74 @@
+use pest::Parser;
+use std::char;
+
+#[derive(Parser)]
+#[grammar = "html.pest"]
+struct HtmlParser;
+
+/// Extract the text content of an html document.
+///
+/// Close to BeautifulSoup's `get_text()` as used by `strip_html.sh`: tags, comments,
+/// declarations and `<script>`/`<style>` bodies are dropped and every other character is
+/// kept verbatim. Unlike BeautifulSoup only numeric entities and `&amp;`, `&lt;`,
+/// `&gt;`, `&quot;`, `&apos;` and `&nbsp;` are decoded, any other named entity such as
+/// `&ndash;` is kept as written. `&nbsp;` becomes a plain space so the audit grammar's
+/// whitespace rules still apply.
+pub fn strip_html(html: &str) -> String {
+    let document = HtmlParser::parse(Rule::document, html)
+        .expect("html grammar accepts any input")
+        .next()
+        .unwrap();
+    let mut out = String::with_capacity(html.len());
+    document.into_inner().for_each(|pair| match pair.as_rule() {
+        Rule::TEXT | Rule::STRAY => out.push_str(pair.as_str()),
+        Rule::ENTITY => match decode_entity(pair.clone().into_inner().next().unwrap()) {
+            Some(c) => out.push(c),
+            None => out.push_str(pair.as_str()),
+        },
+        _ => (),
+    });
+    out
+}
+
+/// Decode the body of an `ENTITY`, `None` if it is unknown or not a valid character.
+fn decode_entity(pair: pest::iterators::Pair<'_, Rule>) -> Option<char> {
+    match pair.as_rule() {
+        Rule::DECIMAL => pair.as_str().parse::<u32>().ok().and_then(char::from_u32),
+        Rule::HEX => u32::from_str_radix(pair.as_str(), 16)
+            .ok()
+            .and_then(char::from_u32),
+        Rule::NAMED => match pair.as_str() {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => None,
+        },
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_html;
+
+    #[test]
+    fn strips_tags_and_comments() {
+        let html = "<!DOCTYPE html><html><!-- hi --><body class=\"a>b\"><p>GRADUATION DATE:</p> <b>05/20/21</b></body></html>";
+        assert_eq!(strip_html(html), "GRADUATION DATE: 05/20/21");
+    }
+
+    #[test]
+    fn drops_script_and_style() {
+        let html = "<style>p { color: red; }</style><script type='text/javascript'>if (a < b) {}</script>OK";
+        assert_eq!(strip_html(html), "OK");
+    }
+
+    #[test]
+    fn decodes_entities() {
+        let html = "Art &amp; Design&nbsp;&#40;ND&#x29; &bogus; &ndash; a < b";
+        assert_eq!(strip_html(html), "Art & Design (ND) &bogus; &ndash; a < b");
+    }
+}
@@ -1,14 +1,35 @@
 #![allow(unused)]
+//! Parser for Northeastern University Web Audits.
 //!
+//! ```no_run
+//! use audit_parser::AuditParser;
+//!
+//! let html = std::fs::read_to_string("Web Audit.html").unwrap();
+//! let audit = AuditParser::parse_audit_html(&html).unwrap();
+//! println!("{}: {}", audit.majors().join(", "), audit.gpa());
+//! ```
+//!
+//! The parsed [`Audit`](Audit) is built from the types in [`constants`](constants).
+//! [`evaluation`](evaluation) checks it against a major's requirements, as loaded by
+//! [`registry`](registry). [`requisites`](requisites) checks a planned schedule.
+//! C callers use [`ffi`](ffi) and `audit.h` instead.
 #![warn(missing_debug_implementations, rust_2018_idioms, missing_docs)]
 #![warn(clippy::all)]
 
 #[macro_use]
 extern crate pest_derive;
 
-mod constants;
+pub mod constants;
+mod error;
+pub mod evaluation;
 mod html_parser;
+mod html_text;
+pub mod registry;
+pub mod requisites;
+
 use chrono::prelude::*;
+pub use error::{AuditError, Location, MajorError};
+pub use html_parser::{Audit, AuditParser, Rule};
 
 /// Module for FFI Receiving/Freeing
 pub mod ffi {
@@ -16,30 +37,85 @@ pub mod ffi {
     use std::{
         ffi::{CStr, CString},
         os::raw::c_char,
+        panic::{self, AssertUnwindSafe},
+        ptr,
     };
 
+    /// Outcome of [`parse_web_audit_ffi`](parse_web_audit_ffi)
+    ///
+    /// `audit.h` prefixes the variants with the enum name, i.e. `AUDIT_STATUS_SUCCESS`.
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum AuditStatus {
+        /// `payload` is the audit as json
+        Success,
+        /// The source pointer was null, `payload` is null
+        NullInput,
+        /// The source was not valid UTF-8, `payload` is an error message
+        InvalidUtf8,
+        /// The source is not a Web Audit, `payload` is an error message
+        ParseFailed,
+        /// The audit could not be converted to json, `payload` is an error message
+        SerializeFailed,
+        /// The parser panicked, `payload` is an error message
+        Panicked,
+    }
+
+    /// Status and payload returned across the C boundary
+    #[repr(C)]
+    #[derive(Debug)]
+    pub struct AuditResult {
+        /// What happened, only [`AuditStatus::Success`](AuditStatus::Success) carries an audit
+        pub status: AuditStatus,
+        /// Json or error message depending on `status`, free with [`free_as_json`](free_as_json)
+        pub payload: *mut c_char,
+    }
+
+    impl AuditResult {
+        fn new(status: AuditStatus, payload: String) -> Self {
+            // Error messages may quote the input, which can contain interior NULs
+            let payload = CString::new(payload.replace('\0', "")).unwrap_or_default();
+            Self {
+                status,
+                payload: payload.into_raw(),
+            }
+        }
+    }
+
     /// Given a pointer to a C-String, parse a NEU Web Audit
     /// # Safety
     /// This function receives a pointer to a string it does not own
-    /// It verifies that the pointer can be cast in to a c_str and converted in to
-    /// a rust string, erroring at any point of failure in between.
-    /// It parses the string as an audit. Returning a new c_string pointer to the result
-    /// as json. The user must guarantee that [`free_as_json`](free_as_json) is called on the returned value
+    /// It verifies that the pointer is not null and converts it in to a rust string,
+    /// returning a status other than `Success` at any point of failure in between.
+    /// Panics are caught and never unwind in to the caller.
+    /// On success the payload is the audit as json. The user must guarantee that
+    /// [`free_as_json`](free_as_json) is called on the returned payload
     #[no_mangle]
-    pub unsafe extern "C" fn parse_web_audit_ffi(src: *const c_char) -> *mut c_char {
+    pub unsafe extern "C" fn parse_web_audit_ffi(src: *const c_char) -> AuditResult {
+        if src.is_null() {
+            return AuditResult {
+                status: AuditStatus::NullInput,
+                payload: ptr::null_mut(),
+            };
+        }
         let c_str = CStr::from_ptr(src);
         let recipient = match c_str.to_str() {
-            Err(_) => "failed to convert from c string to rust string",
+            Err(err) => return AuditResult::new(AuditStatus::InvalidUtf8, err.to_string()),
             Ok(string) => string,
         };
-        let contents = AuditParser::parse_audit(recipient);
-        let as_json = match serde_json::to_string_pretty(&contents.unwrap()) {
-            Err(_) => "failed to convert to json".into(),
-            Ok(val) => val,
-        };
-        CString::new(as_json)
-            .expect("Could not convert in to cstring.")
-            .into_raw()
+        let parsed = panic::catch_unwind(AssertUnwindSafe(|| {
+            let contents = match AuditParser::parse_audit(recipient) {
+                Err(err) => return AuditResult::new(AuditStatus::ParseFailed, err.to_string()),
+                Ok(val) => val,
+            };
+            match serde_json::to_string_pretty(&contents) {
+                Err(err) => AuditResult::new(AuditStatus::SerializeFailed, err.to_string()),
+                Ok(val) => AuditResult::new(AuditStatus::Success, val),
+            }
+        }));
+        parsed.unwrap_or_else(|_| {
+            AuditResult::new(AuditStatus::Panicked, "audit parser panicked".into())
+        })
     }
 
     #[no_mangle]
@@ -50,7 +126,7 @@ pub mod ffi {
         if s.is_null() {
             return;
         }
-        CString::from_raw(s);
+        drop(CString::from_raw(s));
     }
 }
 
@@ -59,19 +135,38 @@ pub use ffi::*;
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::html_parser::AuditParser;
-    use fs::File;
-    use pest::Parser;
+    use crate::html_parser::tests::{fixture, FIXTURE};
     use serde_json::to_writer_pretty;
-    use std::fs;
+    use std::fs::File;
 
     #[test]
     fn pest_it_works() {
-        let unparsed_file = fs::read_to_string("/Users/dustinknopoff/Downloads/WebAudit2.txt")
-            .expect("cannot read file");
-
-        let audit = AuditParser::parse_audit(&unparsed_file).unwrap();
+        let audit = fixture();
         let mut output = File::create("./Web Audit.json").unwrap();
         to_writer_pretty(output, &audit).unwrap();
     }
+
+    #[test]
+    fn ffi_reports_status() {
+        let audit = std::ffi::CString::new(FIXTURE).unwrap();
+        unsafe {
+            let result = parse_web_audit_ffi(audit.as_ptr());
+            assert_eq!(result.status, AuditStatus::Success);
+            free_as_json(result.payload);
+
+            let garbage = std::ffi::CString::new("not an audit").unwrap();
+            let result = parse_web_audit_ffi(garbage.as_ptr());
+            assert_eq!(result.status, AuditStatus::ParseFailed);
+            free_as_json(result.payload);
+
+            let invalid = std::ffi::CString::new(vec![0xff, 0xfe]).unwrap();
+            let result = parse_web_audit_ffi(invalid.as_ptr());
+            assert_eq!(result.status, AuditStatus::InvalidUtf8);
+            free_as_json(result.payload);
+
+            let result = parse_web_audit_ffi(std::ptr::null());
+            assert_eq!(result.status, AuditStatus::NullInput);
+            assert!(result.payload.is_null());
+        }
+    }
 }
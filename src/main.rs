@@ -0,0 +1,179 @@
+//! Command line interface, parses a Web Audit to json.
+#![warn(missing_debug_implementations, rust_2018_idioms)]
+#![warn(clippy::all)]
+
+use audit_parser::{constants::parser_types::InitialScheduleRep, Audit, AuditError, AuditParser};
+use serde::Serialize;
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, Read, Write},
+    process,
+};
+
+const USAGE: &str = "\
+Usage: audit-parser [OPTIONS] [INPUT]
+
+Parse a NEU Web Audit, saved as html or stripped to text, to json.
+Reads INPUT, or stdin when INPUT is missing or `-`.
+
+Options:
+    -o, --output FILE   Write json to FILE instead of stdout
+    -c, --compact       Write compact json instead of pretty printing
+    -g, --graduatenu    Write GraduateNU's InitialScheduleRep instead of the audit
+        --html          Treat the input as html
+        --text          Treat the input as text stripped from the html
+    -h, --help          Print this message";
+
+/// How the input should be read
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Format {
+    /// Html if the input starts with a tag
+    Detect,
+    Html,
+    Text,
+}
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    input: Option<String>,
+    output: Option<String>,
+    compact: bool,
+    graduatenu: bool,
+    format: Format,
+}
+
+impl Options {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Options {
+            input: None,
+            output: None,
+            compact: false,
+            graduatenu: false,
+            format: Format::Detect,
+        };
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-o" | "--output" => match args.next() {
+                    Some(file) => options.output = Some(file),
+                    None => return Err(format!("{} requires a file", arg)),
+                },
+                "-c" | "--compact" => options.compact = true,
+                "-g" | "--graduatenu" => options.graduatenu = true,
+                "--html" => options.format = Format::Html,
+                "--text" => options.format = Format::Text,
+                "-" => options.input = None,
+                flag if flag.starts_with('-') => return Err(format!("unknown option {}", flag)),
+                _ if options.input.is_some() => return Err(format!("unexpected argument {}", arg)),
+                _ => options.input = Some(arg),
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn parse(source: &str, format: Format) -> Result<Audit<'_>, AuditError> {
+    let is_html = match format {
+        Format::Detect => source.trim_start().starts_with('<'),
+        Format::Html => true,
+        Format::Text => false,
+    };
+    if is_html {
+        AuditParser::parse_audit_html(source)
+    } else {
+        AuditParser::parse_audit(source)
+    }
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    let source = match &options.input {
+        Some(file) => {
+            fs::read_to_string(file).map_err(|err| format!("cannot read {}: {}", file, err))?
+        }
+        None => {
+            let mut source = String::new();
+            io::stdin()
+                .read_to_string(&mut source)
+                .map_err(|err| format!("cannot read stdin: {}", err))?;
+            source
+        }
+    };
+    let audit = parse(&source, options.format).map_err(|err| err.to_string())?;
+    let output: Box<dyn Write> = match &options.output {
+        Some(file) => {
+            Box::new(File::create(file).map_err(|err| format!("cannot create {}: {}", file, err))?)
+        }
+        None => Box::new(io::stdout()),
+    };
+    let written = if options.graduatenu {
+        write_json(output, &InitialScheduleRep::from(&audit), options.compact)
+    } else {
+        write_json(output, &audit, options.compact)
+    };
+    written.map_err(|err| format!("cannot write json: {}", err))
+}
+
+fn write_json(output: impl Write, value: &impl Serialize, compact: bool) -> serde_json::Result<()> {
+    if compact {
+        serde_json::to_writer(output, value)
+    } else {
+        serde_json::to_writer_pretty(output, value)
+    }
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
+    let options = match Options::parse(args) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+    if let Err(err) = run(&options) {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_options() {
+        let options = args(&["--compact", "-o", "out.json", "-g", "--html", "audit.html"]).unwrap();
+        assert_eq!(
+            options,
+            Options {
+                input: Some("audit.html".into()),
+                output: Some("out.json".into()),
+                compact: true,
+                graduatenu: true,
+                format: Format::Html,
+            }
+        );
+        assert_eq!(args(&["-"]).unwrap().input, None);
+        assert!(args(&["-o"]).is_err());
+        assert!(args(&["--pretty"]).is_err());
+        assert!(args(&["a.txt", "b.txt"]).is_err());
+    }
+
+    #[test]
+    fn detects_html() {
+        let html = fs::read_to_string("tests/fixtures/WebAudit.html").expect("cannot read file");
+        let text = fs::read_to_string("tests/fixtures/WebAudit.txt").expect("cannot read file");
+
+        assert_eq!(parse(&html, Format::Detect).unwrap().majors().len(), 1);
+        assert_eq!(parse(&text, Format::Detect).unwrap().minors().len(), 1);
+    }
+}
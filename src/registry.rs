@@ -0,0 +1,274 @@
+//! Major definitions loaded from json files on disk.
+use crate::constants::{
+    courses::{CourseRange, Requirement},
+    majors::{Major, MajorRequirement},
+};
+use crate::error::MajorError;
+use crate::html_parser::Audit;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Major definitions by name and catalog `year_version`, read once from a directory.
+///
+/// Every `*.json` file below the directory holds one [`Major`](Major), nested directories
+/// i.e. one per catalog year are searched too.
+#[derive(Debug, Clone, Default)]
+pub struct MajorRegistry {
+    /// Definitions of each major, by lowercase name then `year_version`
+    majors: BTreeMap<String, BTreeMap<isize, Major>>,
+}
+
+impl MajorRegistry {
+    /// An empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load and validate every definition below `dir`.
+    pub fn load_dir(dir: impl AsRef<Path>) -> Result<Self, MajorError> {
+        let mut registry = Self::new();
+        let mut files = vec![];
+        Self::find_json(dir.as_ref(), &mut files)?;
+        files.sort();
+        for file in files {
+            registry.insert(Self::load_file(&file)?);
+        }
+        Ok(registry)
+    }
+
+    /// Load and validate a single definition.
+    pub fn load_file(file: impl AsRef<Path>) -> Result<Major, MajorError> {
+        let file = file.as_ref();
+        let json = fs::read_to_string(file).map_err(|err| MajorError::Io(file.into(), err))?;
+        let deserializer = &mut serde_json::Deserializer::from_str(&json);
+        let major: Major =
+            serde_path_to_error::deserialize(deserializer).map_err(|err| MajorError::Schema {
+                file: file.into(),
+                path: err.path().to_string(),
+                message: err.inner().to_string(),
+            })?;
+        validate(&major).map_err(|(path, message)| MajorError::Invalid {
+            file: file.into(),
+            path,
+            message,
+        })?;
+        Ok(major)
+    }
+
+    /// Add a definition, replacing any with the same name and `year_version`.
+    pub fn insert(&mut self, major: Major) {
+        self.majors
+            .entry(major.name.to_lowercase())
+            .or_default()
+            .insert(major.year_version, major);
+    }
+
+    /// Every definition, by name then `year_version`.
+    pub fn iter(&self) -> impl Iterator<Item = &Major> {
+        self.majors.values().flat_map(|years| years.values())
+    }
+
+    /// Definition of `name` for catalog year `year`, falling back to the latest earlier
+    /// catalog year.
+    ///
+    /// `name` is matched ignoring case, either in full or up to the degree the registrar
+    /// omits, so "Computer Science" finds "Computer Science, BSCS".
+    pub fn get(&self, name: &str, year: isize) -> Option<&Major> {
+        let name = name.trim().to_lowercase();
+        let years = self.majors.get(&name).or_else(|| {
+            self.majors
+                .iter()
+                .find(|(full, _)| full.split(',').next().map(str::trim) == Some(name.as_str()))
+                .map(|(_, years)| years)
+        })?;
+        years.range(..=year).next_back().map(|(_, major)| major)
+    }
+
+    /// Definitions of the majors declared on `audit`, for its catalog year.
+    pub fn for_audit(&self, audit: &Audit<'_>) -> Vec<&Major> {
+        let year = audit.catalog_year().year_version();
+        audit
+            .majors()
+            .iter()
+            .filter_map(|major| self.get(major, year))
+            .collect()
+    }
+
+    fn find_json(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), MajorError> {
+        let entries = fs::read_dir(dir).map_err(|err| MajorError::Io(dir.into(), err))?;
+        for entry in entries {
+            let path = entry.map_err(|err| MajorError::Io(dir.into(), err))?.path();
+            if path.is_dir() {
+                Self::find_json(&path, files)?;
+            } else if path.extension().is_some_and(|ext| ext == "json") {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Check what the schema cannot, returning the json path and problem of the first error.
+fn validate(major: &Major) -> Result<(), (String, String)> {
+    let fail = |path: String, message: &str| Err((path, message.to_string()));
+    if major.name.trim().is_empty() {
+        return fail("name".into(), "is empty");
+    }
+    if !(1900..=2999).contains(&major.year_version) {
+        return fail("year_version".into(), "is not a year");
+    }
+    for (i, name) in major.requirement_groups.iter().enumerate() {
+        if !major.requirement_group_map.contains_key(name) {
+            return fail(
+                format!("requirement_groups[{}]", i),
+                "is missing from requirement_group_map",
+            );
+        }
+    }
+    if let Some(concentrations) = &major.concentrations {
+        if concentrations.min_options > concentrations.max_options {
+            return fail(
+                "concentrations.min_options".into(),
+                "is more than max_options",
+            );
+        }
+    }
+    for (name, group) in &major.requirement_group_map {
+        let (kind, min, max) = match group {
+            MajorRequirement::And(section) => {
+                ("And", section.num_credits_min, section.num_credits_max)
+            }
+            MajorRequirement::Or(section) => {
+                ("Or", section.num_credits_min, section.num_credits_max)
+            }
+            MajorRequirement::Range(range) => {
+                ("Range", range.num_credits_min, range.num_credits_max)
+            }
+        };
+        let path = format!("requirement_group_map.{}.{}", name, kind);
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                return fail(
+                    format!("{}.num_credits_min", path),
+                    "is more than num_credits_max",
+                );
+            }
+        }
+        let ranges: Vec<&CourseRange> = match group {
+            MajorRequirement::And(section) | MajorRequirement::Or(section) => {
+                let mut ranges = vec![];
+                section
+                    .requirements
+                    .iter()
+                    .for_each(|r| collect_ranges(r, &mut ranges));
+                ranges
+            }
+            MajorRequirement::Range(range) => range.requirements.iter().collect(),
+        };
+        for range in ranges.iter().flat_map(|range| &range.ranges) {
+            if range.id_range_start > range.id_range_end {
+                return fail(
+                    format!("{}.requirements", path),
+                    &format!(
+                        "range {} {} to {} is empty",
+                        range.subject, range.id_range_start, range.id_range_end
+                    ),
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn collect_ranges<'m>(requirement: &'m Requirement, ranges: &mut Vec<&'m CourseRange>) {
+    match requirement {
+        Requirement::OrCourse(requirements) | Requirement::AndCourse(requirements) => requirements
+            .iter()
+            .for_each(|requirement| collect_ranges(requirement, ranges)),
+        Requirement::CourseRange(range) => ranges.push(range),
+        Requirement::RequiredCourse(_) => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html_parser::AuditParser;
+
+    /// A directory of its own for each test run, removed even when the test fails.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("{}-{}", name, std::process::id()));
+            fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn loads_majors() {
+        let registry = MajorRegistry::load_dir("tests/fixtures/majors").unwrap();
+        assert_eq!(registry.iter().count(), 2);
+
+        let major = registry.get("computer science", 2020).unwrap();
+        assert_eq!(major.name, "Computer Science, BSCS");
+        assert_eq!(major.year_version, 2019);
+        assert_eq!(
+            registry.get("Computer Science", 2021).unwrap().year_version,
+            2021
+        );
+        assert!(registry.get("Computer Science", 2018).is_none());
+        assert!(registry.get("Mathematics", 2021).is_none());
+    }
+
+    #[test]
+    fn reports_schema_errors() {
+        let dir = TempDir::new("audit-parser-bad-majors");
+        let file = dir.0.join("bad.json");
+        let major = fs::read_to_string("tests/fixtures/majors/2019/computer-science.json")
+            .unwrap()
+            .replace("\"num_credits_min\": 8", "\"num_credits_min\": \"eight\"");
+        fs::write(&file, major).unwrap();
+
+        match MajorRegistry::load_dir(&dir.0) {
+            Err(MajorError::Schema {
+                file: bad, path, ..
+            }) => {
+                assert_eq!(bad, file);
+                assert_eq!(
+                    path,
+                    "requirement_group_map.Electives.Range.num_credits_min"
+                );
+            }
+            other => panic!("expected a schema error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn finds_majors_for_audit() {
+        let registry = MajorRegistry::load_dir("tests/fixtures/majors").unwrap();
+        let year_version = |catalog: &str| {
+            let audit = format!(
+                "GRADUATION DATE: 05/20/24 CATALOG YEAR: {}\nComputer Science - Major\n",
+                catalog
+            );
+            let audit = AuditParser::parse_audit(&audit).unwrap();
+            let majors = registry.for_audit(&audit);
+            majors
+                .iter()
+                .map(|major| major.year_version)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(year_version("202021"), vec![2019]);
+        assert_eq!(year_version("202122"), vec![2021]);
+        assert!(year_version("201819").is_empty());
+    }
+}
@@ -0,0 +1,203 @@
+//! Prerequisite and corequisite checking for a planned schedule.
+use crate::constants::{
+    courses::{NeuPreqs, Prereq},
+    schedule::{Schedule, ScheduleCourse, ScheduleTerm, Term},
+    warnings::{CourseWarning, Warning, WarningContainer},
+};
+use std::collections::HashSet;
+
+/// Check every course of `schedule`, term by term.
+///
+/// Prerequisites must be taken in an earlier term and corequisites in the same term.
+/// Requisites missing from the catalog can never be taken and are ignored.
+pub fn check_schedule(schedule: &Schedule) -> WarningContainer {
+    let mut taken = HashSet::new();
+    let mut course_warnings = vec![];
+    for term in terms(schedule) {
+        let this_term: HashSet<String> = term.classes.iter().map(key).collect();
+        for course in &term.classes {
+            if let Some(missing) = unmet(&course.pre_reqs, &taken) {
+                course_warnings.push(warning(
+                    course,
+                    term,
+                    format!(
+                        "{} {}: prerequisite {} not taken",
+                        course.subject, course.class_id, missing
+                    ),
+                ));
+            }
+            if let Some(missing) = unmet(&course.co_reqs, &this_term) {
+                course_warnings.push(warning(
+                    course,
+                    term,
+                    format!(
+                        "{} {}: corequisite {} not taken in the same term",
+                        course.subject, course.class_id, missing
+                    ),
+                ));
+            }
+        }
+        taken.extend(this_term);
+    }
+    WarningContainer {
+        normal_warnings: vec![],
+        course_warnings,
+    }
+}
+
+/// Terms of `schedule` in chronological order, placeholders skipped.
+fn terms(schedule: &Schedule) -> impl Iterator<Item = &Term> {
+    schedule
+        .years
+        .iter()
+        .filter_map(move |year| schedule.year_map.get(year))
+        .flat_map(|year| vec![&year.fall, &year.spring, &year.summer1, &year.summer2])
+        .filter_map(|term| match term {
+            ScheduleTerm::Term(term) => Some(term),
+            ScheduleTerm::Dnd(_) => None,
+        })
+}
+
+/// "SUBJECT CLASSID", as in the course catalog.
+fn key(course: &ScheduleCourse) -> String {
+    format!("{} {}", course.subject, course.class_id)
+}
+
+/// Description of the requisites in `requisites` that are not in `taken`.
+fn unmet(requisites: &Option<NeuPreqs>, taken: &HashSet<String>) -> Option<String> {
+    let met =
+        |prereq: &&Prereq| prereq.missing == Some(true) || taken.contains(&prereq.to_string());
+    let list = |prereqs: &[&Prereq], joiner: &str| {
+        prereqs
+            .iter()
+            .map(|prereq| prereq.to_string())
+            .collect::<Vec<_>>()
+            .join(joiner)
+    };
+    match requisites.as_ref()? {
+        NeuPreqs::One(prereq) if !met(&prereq) => Some(prereq.to_string()),
+        NeuPreqs::And(prereqs) => {
+            let missing: Vec<_> = prereqs.iter().filter(|prereq| !met(prereq)).collect();
+            Some(list(&missing, " and ")).filter(|_| !missing.is_empty())
+        }
+        NeuPreqs::Or(prereqs) if !prereqs.is_empty() && !prereqs.iter().any(|p| met(&p)) => {
+            Some(list(&prereqs.iter().collect::<Vec<_>>(), " or "))
+        }
+        _ => None,
+    }
+}
+
+fn warning(course: &ScheduleCourse, term: &Term, message: String) -> CourseWarning {
+    CourseWarning {
+        subject: course.subject.clone(),
+        class_id: course.class_id.parse().unwrap_or_default(),
+        warning: Warning {
+            message,
+            term_id: term.term_id,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{
+        abbreviations::Season,
+        schedule::{ScheduleYear, Status},
+        terms::TermId,
+    };
+    use std::convert::TryFrom;
+
+    fn prereq(subject: &str, class_id: &str) -> Prereq {
+        Prereq {
+            class_id: class_id.into(),
+            subject: subject.into(),
+            missing: None,
+        }
+    }
+
+    fn course(subject: &str, class_id: &str, pre_reqs: Option<NeuPreqs>) -> ScheduleCourse {
+        ScheduleCourse {
+            name: String::new(),
+            class_id: class_id.into(),
+            subject: subject.into(),
+            pre_reqs,
+            co_reqs: None,
+            num_credits_min: 4,
+            num_credits_max: 4,
+        }
+    }
+
+    fn term(season: Season, term_id: isize, classes: Vec<ScheduleCourse>) -> ScheduleTerm {
+        ScheduleTerm::Term(Term {
+            season,
+            year: 20,
+            term_id: TermId::try_from(term_id).unwrap(),
+            id: term_id,
+            status: Status::CLASSES,
+            classes,
+        })
+    }
+
+    #[test]
+    fn warns_about_unmet_requisites() {
+        let mut fundies = course("CS", "2500", None);
+        fundies.co_reqs = Some(NeuPreqs::One(prereq("CS", "2501")));
+        let ood = course(
+            "CS",
+            "3500",
+            Some(NeuPreqs::And(vec![
+                prereq("CS", "2510"),
+                prereq("CS", "2500"),
+            ])),
+        );
+        let algo = course(
+            "CS",
+            "3000",
+            Some(NeuPreqs::Or(vec![
+                prereq("CS", "2510"),
+                prereq("CS", "1800"),
+            ])),
+        );
+        let mut missing = prereq("CS", "1000");
+        missing.missing = Some(true);
+        let discrete = course("CS", "1800", Some(NeuPreqs::One(missing)));
+        let year = ScheduleYear {
+            year: 2021,
+            fall: term(Season::FL, 202110, vec![fundies, discrete]),
+            spring: term(Season::SP, 202130, vec![ood, algo]),
+            summer1: term(Season::S1, 202140, vec![]),
+            summer2: ScheduleTerm::Dnd("summer2".into()),
+            is_summer_full: false,
+        };
+        let schedule = Schedule {
+            years: vec![2021],
+            year_map: vec![(2021, year)].into_iter().collect(),
+            id: "0".into(),
+        };
+
+        let warnings = check_schedule(&schedule);
+        let messages: Vec<_> = warnings
+            .course_warnings
+            .iter()
+            .map(|warning| {
+                (
+                    warning.class_id,
+                    isize::from(warning.warning.term_id),
+                    &warning.warning.message[..],
+                )
+            })
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    2500,
+                    202110,
+                    "CS 2500: corequisite CS 2501 not taken in the same term"
+                ),
+                (3500, 202130, "CS 3500: prerequisite CS 2510 not taken"),
+            ]
+        );
+    }
+}